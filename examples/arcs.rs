@@ -0,0 +1,123 @@
+//! This example demonstrates rings and arcs drawn with the `ArcUiMaterial`: a cooldown ring with flat caps that empties
+//! over time, a spinner with round caps, and a radial timer drawn over a track.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, animate_arcs)
+        .run();
+}
+
+const RING_SIZE: f32 = 140.0;
+
+/// Component for animating an arc
+#[derive(Component)]
+enum ArcAnimation {
+    /// Empties the arc over the given number of seconds, then starts over
+    Cooldown(f32),
+    /// Rotates the arc at the given speed, in turns per second
+    Spin(f32),
+    /// Fills the arc over the given number of seconds, then starts over
+    Timer(f32),
+}
+
+fn setup(mut commands: Commands, mut materials: ResMut<Assets<ArcUiMaterial>>) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let track_color: LinearRgba = Srgba::hex("#2a2e37").unwrap().into();
+
+    // A cooldown ring with flat caps and no track
+    let cooldown = materials.add(ArcUiMaterial {
+        fill_color: Srgba::hex("#F76161").unwrap().into(),
+        thickness: 16.,
+        cap: RoundUiArcCap::Flat,
+        ..default()
+    });
+
+    // A loading spinner covering a quarter of the ring
+    let spinner = materials.add(ArcUiMaterial {
+        track_color: LinearRgba::new(1., 1., 1., 0.05),
+        fill_color: Srgba::hex("#ffd84a").unwrap().into(),
+        sweep: 0.25 * TAU,
+        thickness: 10.,
+        ..default()
+    });
+
+    // A radial timer over a track
+    let timer = materials.add(ArcUiMaterial {
+        track_color,
+        fill_color: Srgba::hex("#5cb3af").unwrap().into(),
+        sweep: 0.,
+        thickness: 20.,
+        ..default()
+    });
+
+    // Spawn the arcs in a row in the middle of the screen
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(60.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(RING_SIZE),
+                height: Val::Px(RING_SIZE),
+                ..default()
+            };
+            for (material, animation) in [
+                (cooldown, ArcAnimation::Cooldown(4.)),
+                (spinner, ArcAnimation::Spin(0.75)),
+                (timer, ArcAnimation::Timer(6.)),
+            ] {
+                p.spawn((
+                    MaterialNodeBundle {
+                        material,
+                        style: style.clone(),
+                        ..default()
+                    },
+                    animation,
+                ));
+            }
+        });
+}
+
+/// Animates the sweep or start angle of each arc
+fn animate_arcs(
+    time: Res<Time>,
+    query: Query<(&ArcAnimation, &Handle<ArcUiMaterial>)>,
+    mut materials: ResMut<Assets<ArcUiMaterial>>,
+) {
+    let seconds = time.elapsed_seconds();
+    for (animation, handle) in query.iter() {
+        let Some(material) = materials.get_mut(handle) else {
+            continue;
+        };
+        match *animation {
+            ArcAnimation::Cooldown(duration) => {
+                material.sweep = (1. - (seconds % duration) / duration) * TAU;
+            }
+            ArcAnimation::Spin(speed) => {
+                material.start_angle = (seconds * speed % 1.) * TAU;
+            }
+            ArcAnimation::Timer(duration) => {
+                material.sweep = (seconds % duration) / duration * TAU;
+            }
+        }
+    }
+}
@@ -34,6 +34,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
                     border_color: LinearRgba::WHITE,
                     border_radius: RoundUiBorder::all(20.).into(),
                     offset: RoundUiOffset::all(6.).into(),
+                    ..default()
                 }),
                 style: Style {
                     width: Val::Percent(50.),
@@ -53,6 +54,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
                         border_color: LinearRgba::WHITE,
                         border_radius: RoundUiBorder::all(20.0).into(),
                         offset: RoundUiOffset::all(6.0).into(),
+                        ..default()
                     }),
                     style: Style {
                         width: Val::Percent(50.),
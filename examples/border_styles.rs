@@ -0,0 +1,125 @@
+//! This example demonstrates dashed and dotted borders, including animated "marching ants", and a stroke drawn around a
+//! raised shape independently of its offset.
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, march_ants)
+        .run();
+}
+
+const SHAPE_SIZE: f32 = 160.0;
+const BORDER_THICKNESS: f32 = 4.0;
+
+/// Speed of the marching ants, in pixels per second
+const MARCH_SPEED: f32 = 20.0;
+
+/// Handle to the material with the animated border
+#[derive(Resource)]
+struct MarchingAnts(Handle<SuperellipseUiMaterial>);
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let border_color: LinearRgba = Srgba::hex("#9aa3b5").unwrap().into();
+
+    // A drop zone with a dashed border
+    let drop_zone = materials.add(RoundRectUiMaterial {
+        background_color: Srgba::hex("#2a2e37").unwrap().into(),
+        border_color,
+        border_style: RoundUiBorderStyle::dashed(16., 8.),
+        border_radius: RoundUiBorder::all(48.0).into(),
+        offset: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
+    });
+
+    // An empty slot with a dotted border
+    let empty_slot = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color: LinearRgba::NONE,
+        border_color,
+        border_style: RoundUiBorderStyle::dotted(6.),
+        border_radius: RoundUiBorder::all(80.0).into(),
+        border_thickness: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
+    });
+
+    // A selection outline with marching ants
+    let marching_ants = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#2f6fe4").unwrap().into(),
+        border_color: LinearRgba::WHITE,
+        border_style: RoundUiBorderStyle::dashed(8., 8.),
+        border_radius: RoundUiBorder::all(80.0).into(),
+        border_thickness: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
+    });
+    commands.insert_resource(MarchingAnts(marching_ants.clone()));
+
+    // A raised tile with a stroke around both its face and its offset
+    let outlined_tile = materials.add(RoundRectUiMaterial {
+        background_color: Srgba::hex("#F76161").unwrap().into(),
+        border_color: Srgba::hex("#A53A3D").unwrap().into(),
+        border_radius: RoundUiBorder::all(48.0).into(),
+        offset: RoundUiOffset::bottom(12.).into(),
+        stroke_color: LinearRgba::WHITE,
+        stroke_width: BORDER_THICKNESS,
+        ..default()
+    });
+
+    // Spawn the nodes in a row in the middle of the screen
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(60.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(SHAPE_SIZE),
+                height: Val::Px(SHAPE_SIZE),
+                ..default()
+            };
+            for material in [drop_zone, outlined_tile] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+            for material in [empty_slot, marching_ants] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+        });
+}
+
+/// Moves the dashes of the marching ants along the border
+fn march_ants(
+    time: Res<Time>,
+    marching_ants: Res<MarchingAnts>,
+    mut materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    if let Some(material) = materials.get_mut(&marching_ants.0) {
+        let phase = time.elapsed_seconds_wrapped() * MARCH_SPEED;
+        material.border_style = material.border_style.with_phase(phase);
+    }
+}
@@ -1,4 +1,7 @@
 //! This example demonstrates using the plugin with interactive UI elements, such as buttons.
+//!
+//! The buttons can also be used with the keyboard or a gamepad: move focus with the arrow keys, Tab or the D-pad, and
+//! press the focused button with Enter or the South button.
 
 use bevy::{app::AppExit, prelude::*};
 
@@ -6,10 +9,13 @@ use bevy_round_ui::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
-        .init_resource::<ButtonStyle>()
+        .add_plugins((
+            DefaultPlugins,
+            BevyRoundUiDefaultPlugins,
+            RoundUiFocusPlugin,
+        ))
         .add_systems(Startup, setup)
-        .add_systems(Update, (handle_button_interactions, handle_button_actions))
+        .add_systems(Update, handle_button_actions)
         .run();
 }
 
@@ -21,79 +27,68 @@ const PANEL_WIDTH: f32 = 300.0;
 const BUTTON_HEIGHT: f32 = 40.0;
 const BUTTON_OFFSET_SIZE: f32 = 5.0;
 
-/// Resource containing styles for different button states.
-#[derive(Resource)]
-pub struct ButtonStyle {
-    pub default_material: Handle<RoundRectUiMaterial>,
-    pub default_padding: UiRect,
-    pub hover_material: Handle<RoundRectUiMaterial>,
-    pub hover_padding: UiRect,
-    pub press_material: Handle<RoundRectUiMaterial>,
-    pub press_padding: UiRect,
-}
-
-impl FromWorld for ButtonStyle {
-    fn from_world(world: &mut World) -> Self {
-        let mut materials = world
-            .get_resource_mut::<Assets<RoundRectUiMaterial>>()
-            .expect("Failed to get Assets<RoundRectMaterial>");
-
-        let border_radius = RoundUiBorder::all(15.);
-
-        Self {
-            default_material: materials.add(RoundRectUiMaterial {
-                background_color: Srgba::hex("#F76161").unwrap().into(),
-                border_color: Srgba::hex("#A53A3D").unwrap().into(),
-                border_radius: border_radius.into(),
-                offset: RoundUiOffset::bottom(BUTTON_OFFSET_SIZE).into(),
-            }),
-            default_padding: UiRect::bottom(Val::Px(BUTTON_OFFSET_SIZE)),
-            hover_material: materials.add(RoundRectUiMaterial {
-                background_color: Srgba::hex("#F61A39").unwrap().into(),
-                border_color: Srgba::hex("#A0102A").unwrap().into(),
-                border_radius: border_radius.into(),
-                offset: RoundUiOffset::bottom(BUTTON_OFFSET_SIZE).into(),
-            }),
-            hover_padding: UiRect::bottom(Val::Px(BUTTON_OFFSET_SIZE)),
-            press_material: materials.add(RoundRectUiMaterial {
-                background_color: Srgba::hex("#A0102A").unwrap().into(),
-                border_color: LinearRgba::NONE,
-                border_radius: border_radius.into(),
-                offset: RoundUiOffset::top(BUTTON_OFFSET_SIZE).into(),
-            }),
-            press_padding: UiRect::top(Val::Px(BUTTON_OFFSET_SIZE)),
-        }
-    }
+/// Creates the materials for each state of the buttons.
+fn button_style(
+    materials: &mut Assets<SuperellipseUiMaterial>,
+) -> RoundUiInteractionStyle<SuperellipseUiMaterial> {
+    let border_radius = RoundUiBorder::all(15.);
+
+    RoundUiInteractionStyle::new(materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#F76161").unwrap().into(),
+        border_color: Srgba::hex("#A53A3D").unwrap().into(),
+        border_radius: border_radius.into(),
+        offset: RoundUiOffset::bottom(BUTTON_OFFSET_SIZE).into(),
+        ..default()
+    }))
+    .with_hovered(materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#F61A39").unwrap().into(),
+        border_color: Srgba::hex("#A0102A").unwrap().into(),
+        border_radius: border_radius.into(),
+        offset: RoundUiOffset::bottom(BUTTON_OFFSET_SIZE).into(),
+        ..default()
+    }))
+    // The pressed material moves the offset to the top, so the button looks pushed in
+    .with_pressed(materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#A0102A").unwrap().into(),
+        border_color: LinearRgba::NONE,
+        border_radius: border_radius.into(),
+        offset: RoundUiOffset::top(BUTTON_OFFSET_SIZE).into(),
+        ..default()
+    }))
+    .with_disabled(materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#B8A3A3").unwrap().into(),
+        border_color: Srgba::hex("#8A7575").unwrap().into(),
+        border_radius: border_radius.into(),
+        offset: RoundUiOffset::bottom(BUTTON_OFFSET_SIZE).into(),
+        ..default()
+    }))
 }
 
 /// Component defining button actions for handling click events
 #[derive(Component, Debug)]
 enum ButtonAction {
     Play,
+    Online,
     Settings,
     Quit,
 }
 
-/// Component identifying round buttons.
-#[derive(Component)]
-pub struct RoundButton;
-
 /// System that initializes the example.
-fn setup(
-    mut commands: Commands,
-    button_style: Res<ButtonStyle>,
-    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
-) {
+fn setup(mut commands: Commands, mut materials: ResMut<Assets<SuperellipseUiMaterial>>) {
     // Camera so we can see UI
     commands.spawn(Camera2dBundle::default());
 
+    // Define the materials for the buttons
+    let button_style = button_style(&mut materials);
+
     // Define a material for the panel.
     // This material looks like it has a border, because we applied an equal offset to all sides.
-    let panel_material = superellipse_materials.add(SuperellipseUiMaterial {
+    let panel_material = materials.add(SuperellipseUiMaterial {
         background_color: Srgba::hex(PANEL_BACKGROUND_COLOR).unwrap().into(),
         border_color: Srgba::hex(PANEL_BORDER_COLOR).unwrap().into(),
         border_radius: RoundUiBorder::all(20.0).into(),
-        border_thickness: 6.0,
+        border_thickness: RoundUiOffset::all(6.0).into(),
+        ..default()
     });
 
     // Spawn the screen layout, containing a centered panel with menu items
@@ -145,6 +140,12 @@ fn setup(
 
                 // Spawn the buttons
                 spawn_button(p, &button_style, "Play", ButtonAction::Play);
+                spawn_button(
+                    p,
+                    &button_style,
+                    "Online",
+                    (ButtonAction::Online, RoundUiDisabled),
+                );
                 spawn_button(p, &button_style, "Settings", ButtonAction::Settings);
                 spawn_button(p, &button_style, "Quit", ButtonAction::Quit);
             });
@@ -154,15 +155,14 @@ fn setup(
 /// Utility that spawns a new button.
 fn spawn_button(
     parent: &mut ChildBuilder,
-    button_style: &ButtonStyle,
+    button_style: &RoundUiInteractionStyle<SuperellipseUiMaterial>,
     text: impl Into<String>,
     extras: impl Bundle,
 ) -> Entity {
     parent
         .spawn((
-            RoundButton,
             MaterialNodeBundle {
-                material: button_style.default_material.clone(),
+                material: button_style.default.clone(),
                 style: Style {
                     align_items: AlignItems::Center,
                     justify_content: JustifyContent::Center,
@@ -175,6 +175,13 @@ fn spawn_button(
             },
             extras,
             Interaction::default(),
+            button_style.clone(),
+            // Keep the text centered on the top of the button as its offset changes
+            RoundUiAutoPadding::default(),
+            // Animate between the materials of each state
+            RoundUiTransition::new(0.08),
+            // Allow focusing the button with the keyboard and gamepad
+            RoundUiFocusable,
         ))
         .with_children(|p| {
             p.spawn(TextBundle::from_section(
@@ -189,38 +196,13 @@ fn spawn_button(
         .id()
 }
 
-/// System that updates button materials and styles when their interaction state changes
-#[allow(clippy::type_complexity)]
-fn handle_button_interactions(
-    mut interaction_query: Query<
-        (&Interaction, &mut Handle<RoundRectUiMaterial>, &mut Style),
-        (Changed<Interaction>, With<RoundButton>),
-    >,
-    button_style: Res<ButtonStyle>,
-) {
-    for (interaction, mut material, mut style) in &mut interaction_query {
-        let (material_handle, padding) = match *interaction {
-            Interaction::Pressed => (
-                button_style.press_material.clone(),
-                button_style.press_padding,
-            ),
-            Interaction::Hovered => (
-                button_style.hover_material.clone(),
-                button_style.hover_padding,
-            ),
-            Interaction::None => (
-                button_style.default_material.clone(),
-                button_style.default_padding,
-            ),
-        };
-        *material = material_handle;
-        style.padding = padding;
-    }
-}
-
 /// System that handles button click events
+#[allow(clippy::type_complexity)]
 fn handle_button_actions(
-    interaction_query: Query<(&Interaction, &ButtonAction), Changed<Interaction>>,
+    interaction_query: Query<
+        (&Interaction, &ButtonAction),
+        (Changed<Interaction>, Without<RoundUiDisabled>),
+    >,
     mut app_exit_events: EventWriter<AppExit>,
 ) {
     for (interaction, action) in &interaction_query {
@@ -228,6 +210,7 @@ fn handle_button_actions(
             println!("Button pressed: {action:?}");
             match action {
                 ButtonAction::Play => (),
+                ButtonAction::Online => (),
                 ButtonAction::Settings => (),
                 ButtonAction::Quit => {
                     app_exit_events.send(AppExit::Success);
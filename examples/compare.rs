@@ -14,7 +14,7 @@ fn main() {
 
 const PANEL_WIDTH: f32 = 400.0;
 const PANEL_HEIGHT: f32 = 200.0;
-const BORDER_THICKNESS: f32 = 20.;
+const BORDER_THICKNESS: f32 = 10.;
 
 #[derive(Component)]
 pub enum PanelSize {
@@ -43,14 +43,16 @@ fn setup(
         background_color,
         border_color,
         border_radius,
-        border_thickness: BORDER_THICKNESS,
+        border_thickness: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
     });
     // Add the round rect material
     let panel_material_round_rect = old_materials.add(RoundRectUiMaterial {
         background_color,
         border_color,
         border_radius,
-        offset: RoundUiOffset::all(BORDER_THICKNESS / 2.).into(),
+        offset: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
     });
 
     // Spawn help text
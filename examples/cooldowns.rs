@@ -0,0 +1,141 @@
+//! This example demonstrates "clock wipe" cooldowns on ability buttons, drawn with the `sweep_mask` property of round
+//! rect and superellipse materials and driven by a `RoundUiCooldown`. Click a button, or press 1 to 4, to use its
+//! ability.
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, use_abilities)
+        .run();
+}
+
+const BUTTON_SIZE: f32 = 96.0;
+
+/// Marker component for an ability button, with the key that triggers it
+#[derive(Component)]
+struct AbilityButton(KeyCode);
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let overlay_color = LinearRgba::new(0., 0., 0., 0.6);
+
+    // Spawn a row of ability buttons in the middle of the screen. Each button has a material of its own, since the
+    // cooldown writes to it.
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(24.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(BUTTON_SIZE),
+                height: Val::Px(BUTTON_SIZE),
+                ..default()
+            };
+
+            // Round rect buttons with a 3D offset, with cooldowns of different lengths
+            for (key, color, seconds, sweep_mask) in [
+                (
+                    KeyCode::Digit1,
+                    "#F76161",
+                    2.,
+                    RoundUiSweepMask::new(overlay_color, 0.),
+                ),
+                (
+                    KeyCode::Digit2,
+                    "#5cb3af",
+                    5.,
+                    RoundUiSweepMask::new(overlay_color, 0.)
+                        .with_direction(RoundUiSweepDirection::CounterClockwise),
+                ),
+            ] {
+                let background_color = Srgba::hex(color).unwrap();
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: materials.add(RoundRectUiMaterial {
+                            background_color: background_color.into(),
+                            border_color: background_color.darker(0.2).into(),
+                            border_radius: RoundUiBorder::all(20.).into(),
+                            offset: RoundUiOffset::bottom(6.).into(),
+                            sweep_mask,
+                            ..default()
+                        }),
+                        style: style.clone(),
+                        ..default()
+                    },
+                    Interaction::default(),
+                    AbilityButton(key),
+                    RoundUiCooldown::new(seconds),
+                ));
+            }
+
+            // Superellipse buttons with a border, one sweeping from the bottom
+            for (key, color, seconds, sweep_mask) in [
+                (
+                    KeyCode::Digit3,
+                    "#ffd84a",
+                    3.,
+                    RoundUiSweepMask::new(overlay_color, 0.),
+                ),
+                (
+                    KeyCode::Digit4,
+                    "#2f6fe4",
+                    8.,
+                    RoundUiSweepMask::new(overlay_color, 0.).with_start_angle(PI),
+                ),
+            ] {
+                let background_color = Srgba::hex(color).unwrap();
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: superellipse_materials.add(SuperellipseUiMaterial {
+                            background_color: background_color.into(),
+                            border_color: Srgba::hex("#14161b").unwrap().into(),
+                            border_radius: RoundUiBorder::all(60.).into(),
+                            border_thickness: RoundUiOffset::all(4.).into(),
+                            sweep_mask,
+                            ..default()
+                        }),
+                        style: style.clone(),
+                        ..default()
+                    },
+                    Interaction::default(),
+                    AbilityButton(key),
+                    RoundUiCooldown::new(seconds),
+                ));
+            }
+        });
+}
+
+/// Restarts the cooldown of a button when it is clicked or its key is pressed, if its ability is ready
+fn use_abilities(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(Ref<Interaction>, &AbilityButton, &mut RoundUiCooldown)>,
+) {
+    for (interaction, button, mut cooldown) in query.iter_mut() {
+        let clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+        if (clicked || keys.just_pressed(button.0)) && cooldown.is_ready() {
+            cooldown.restart();
+        }
+    }
+}
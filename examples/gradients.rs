@@ -0,0 +1,157 @@
+//! This example demonstrates using gradients for the background and border of material nodes.
+
+use std::f32::consts::PI;
+
+use bevy::{color::palettes::css, prelude::*};
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+const SHAPE_WIDTH: f32 = 200.0;
+const SHAPE_HEIGHT: f32 = 120.0;
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let blue: LinearRgba = Srgba::hex("#2f6fe4").unwrap().into();
+    let yellow: LinearRgba = Srgba::hex("#f5d02f").unwrap().into();
+
+    let rect_materials = [
+        // A 3D button with vertical gradients for the background and border
+        materials.add(RoundRectUiMaterial {
+            background_gradient: Some(RoundUiGradient::vertical(
+                Srgba::hex("#ff8a8a").unwrap(),
+                Srgba::hex("#e03e45").unwrap(),
+            )),
+            border_gradient: Some(RoundUiGradient::vertical(
+                Srgba::hex("#c24a4d").unwrap(),
+                Srgba::hex("#7d2023").unwrap(),
+            )),
+            border_radius: RoundUiBorder::all(30.0).into(),
+            offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
+        }),
+        // Interpolating in linear RGB
+        materials.add(RoundRectUiMaterial {
+            background_gradient: Some(RoundUiGradient::horizontal(blue, yellow)),
+            border_radius: RoundUiBorder::all(30.0).into(),
+            ..default()
+        }),
+        // Interpolating in Oklab avoids the grey midpoint
+        materials.add(RoundRectUiMaterial {
+            background_gradient: Some(
+                RoundUiGradient::horizontal(blue, yellow)
+                    .with_color_space(RoundUiColorSpace::Oklab),
+            ),
+            border_radius: RoundUiBorder::all(30.0).into(),
+            ..default()
+        }),
+    ];
+
+    // A diagonal, multi-stop gradient on a superellipse panel with a gradient border
+    let panel_material = superellipse_materials.add(SuperellipseUiMaterial {
+        background_gradient: Some(
+            RoundUiGradient::linear(
+                PI * 0.75,
+                [
+                    Srgba::hex("#5cb3af").unwrap(),
+                    Srgba::hex("#3b6fb3").unwrap(),
+                    Srgba::hex("#6a3bb3").unwrap(),
+                ],
+            )
+            .with_color_space(RoundUiColorSpace::Oklab),
+        ),
+        border_gradient: Some(RoundUiGradient::vertical(
+            LinearRgba::WHITE,
+            LinearRgba::NONE,
+        )),
+        border_radius: RoundUiBorder::all(80.0).into(),
+        border_thickness: RoundUiOffset::all(4.).into(),
+        ..default()
+    });
+
+    // A radial spotlight highlight at the top of a button
+    let spotlight_material = materials.add(RoundRectUiMaterial {
+        background_gradient: Some(RoundUiGradient::radial(
+            Vec2::new(0.5, 0.),
+            Vec2::new(0.8, 1.),
+            [
+                Srgba::hex("#8fc8ff").unwrap(),
+                Srgba::hex("#2f6fe4").unwrap(),
+            ],
+        )),
+        border_color: Srgba::hex("#1c4591").unwrap().into(),
+        border_radius: RoundUiBorder::all(30.0).into(),
+        offset: RoundUiOffset::bottom(10.0).into(),
+        ..default()
+    });
+
+    // A rainbow selection ring, using a conic gradient for the border
+    let rainbow = [
+        css::RED,
+        css::YELLOW,
+        css::LIME,
+        css::AQUA,
+        css::BLUE,
+        css::FUCHSIA,
+        css::RED,
+    ];
+    let ring_material = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color: Srgba::hex("#20232a").unwrap().into(),
+        border_gradient: Some(
+            RoundUiGradient::conic(Vec2::splat(0.5), 0., rainbow)
+                .with_color_space(RoundUiColorSpace::Oklab),
+        ),
+        border_radius: RoundUiBorder::all(80.0).into(),
+        border_thickness: RoundUiOffset::all(6.).into(),
+        ..default()
+    });
+
+    // Spawn the nodes in the center of the screen
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(40.),
+                row_gap: Val::Px(40.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(SHAPE_WIDTH),
+                height: Val::Px(SHAPE_HEIGHT),
+                ..default()
+            };
+            for material in rect_materials.into_iter().chain([spotlight_material]) {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+            for material in [panel_material, ring_material] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+        });
+}
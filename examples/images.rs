@@ -0,0 +1,202 @@
+//! This example demonstrates filling material nodes with images, including the output of a camera.
+
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        view::RenderLayers,
+    },
+};
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .add_systems(Startup, setup)
+        .add_systems(Update, rotate_minimap_sprites)
+        .run();
+}
+
+const SHAPE_SIZE: f32 = 160.0;
+const MINIMAP_SIZE: u32 = 256;
+
+/// Marker for the sprites rendered to the minimap
+#[derive(Component)]
+struct MinimapSprite;
+
+fn setup(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    // A wide image, so the difference between the fit modes is visible
+    let stripes = images.add(stripes_image(64, 32));
+
+    // The same image with each fit mode
+    let fit_materials = [
+        RoundUiImageFit::Cover,
+        RoundUiImageFit::Contain,
+        RoundUiImageFit::Stretch,
+        RoundUiImageFit::Tile,
+    ]
+    .map(|image_fit| {
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#2a2d35").unwrap().into(),
+            border_color: Srgba::hex("#12141a").unwrap().into(),
+            border_radius: RoundUiBorder::all(24.0).into(),
+            offset: RoundUiOffset::all(4.0).into(),
+            image: Some(stripes.clone()),
+            image_fit,
+            ..default()
+        })
+    });
+
+    // A round, tinted avatar with a border
+    let avatar_material = superellipse_materials.add(SuperellipseUiMaterial {
+        border_color: LinearRgba::WHITE,
+        border_radius: RoundUiBorder::all(SHAPE_SIZE).into(),
+        border_thickness: RoundUiOffset::all(6.).into(),
+        image: Some(stripes),
+        image_tint: Srgba::hex("#ffb8b8").unwrap().into(),
+        ..default()
+    });
+
+    // A minimap showing the output of a second camera
+    let minimap_image = images.add(render_target_image(MINIMAP_SIZE));
+    let minimap_layer = RenderLayers::layer(1);
+    commands.spawn((
+        Camera2dBundle {
+            camera: Camera {
+                order: -1,
+                target: RenderTarget::Image(minimap_image.clone()),
+                clear_color: ClearColorConfig::Custom(Srgba::hex("#3d6b4f").unwrap().into()),
+                ..default()
+            },
+            ..default()
+        },
+        minimap_layer.clone(),
+    ));
+    for (i, color) in [Srgba::hex("#f5d02f"), Srgba::hex("#2f6fe4")]
+        .into_iter()
+        .enumerate()
+    {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: color.unwrap().into(),
+                    custom_size: Some(Vec2::splat(60.)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(if i == 0 { -60. } else { 60. }, 0., 0.),
+                ..default()
+            },
+            minimap_layer.clone(),
+            MinimapSprite,
+        ));
+    }
+    let minimap_material = superellipse_materials.add(SuperellipseUiMaterial {
+        border_color: Srgba::hex("#12141a").unwrap().into(),
+        border_radius: RoundUiBorder::all(80.0).into(),
+        border_thickness: RoundUiOffset::all(4.).into(),
+        image: Some(minimap_image),
+        ..default()
+    });
+
+    // Spawn the nodes in the center of the screen
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(40.),
+                row_gap: Val::Px(40.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(SHAPE_SIZE),
+                height: Val::Px(SHAPE_SIZE),
+                ..default()
+            };
+            for material in fit_materials {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+            for material in [avatar_material, minimap_material] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+        });
+}
+
+/// Creates an image with diagonal stripes and a frame around the edge
+fn stripes_image(width: u32, height: u32) -> Image {
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let frame = x < 2 || y < 2 || x >= width - 2 || y >= height - 2;
+            let pixel = if frame {
+                [255, 255, 255, 255]
+            } else if (x + y) / 8 % 2 == 0 {
+                [247, 97, 97, 255]
+            } else {
+                [92, 179, 175, 255]
+            };
+            data.extend_from_slice(&pixel);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width,
+            height,
+            ..default()
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Creates an empty image that a camera can render to
+fn render_target_image(size: u32) -> Image {
+    let size = Extent3d {
+        width: size,
+        height: size,
+        ..default()
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+fn rotate_minimap_sprites(time: Res<Time>, mut query: Query<&mut Transform, With<MinimapSprite>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotate_z(time.delta_seconds());
+    }
+}
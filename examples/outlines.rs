@@ -0,0 +1,140 @@
+//! This example demonstrates outlines drawn outside the bounds of round rect and superellipse material nodes, used as a
+//! selection highlight. Click a card to select it.
+//!
+//! The outline doesn't affect the layout, so the cards don't move when the selection changes.
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, select_card)
+        .run();
+}
+
+const CARD_SIZE: f32 = 140.0;
+const OUTLINE_WIDTH: f32 = 3.0;
+const OUTLINE_OFFSET: f32 = 4.0;
+
+/// The outline shown around the selected card
+fn selected_outline() -> RoundUiOutline {
+    RoundUiOutline::new(Srgba::hex("#ffd84a").unwrap(), OUTLINE_WIDTH).with_offset(OUTLINE_OFFSET)
+}
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let background_color: LinearRgba = Srgba::hex("#F76161").unwrap().into();
+    let border_color: LinearRgba = Srgba::hex("#A53A3D").unwrap().into();
+
+    // Spawn a row of cards in the middle of the screen, each with a material of its own so its outline can change
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(40.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(CARD_SIZE),
+                height: Val::Px(CARD_SIZE),
+                ..default()
+            };
+
+            // Round rects with different corners, the first one selected
+            for (i, border_radius) in [
+                RoundUiBorder::all(30.),
+                RoundUiBorder::top_left(60.),
+                RoundUiBorder::all(0.),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: materials.add(RoundRectUiMaterial {
+                            background_color,
+                            border_color,
+                            border_radius: border_radius.into(),
+                            offset: RoundUiOffset::bottom(8.).into(),
+                            outline: if i == 0 {
+                                selected_outline()
+                            } else {
+                                RoundUiOutline::default()
+                            },
+                            ..default()
+                        }),
+                        style: style.clone(),
+                        ..default()
+                    },
+                    Interaction::default(),
+                ));
+            }
+
+            // A superellipse
+            p.spawn((
+                MaterialNodeBundle {
+                    material: superellipse_materials.add(SuperellipseUiMaterial {
+                        background_color,
+                        border_color,
+                        border_radius: RoundUiBorder::all(100.).into(),
+                        offset: RoundUiOffset::bottom(8.).into(),
+                        ..default()
+                    }),
+                    style: style.clone(),
+                    ..default()
+                },
+                Interaction::default(),
+            ));
+        });
+}
+
+/// Moves the outline to the card that was clicked
+fn select_card(
+    interactions: Query<(Entity, &Interaction), Changed<Interaction>>,
+    cards: Query<(Entity, &Handle<RoundRectUiMaterial>)>,
+    superellipse_cards: Query<(Entity, &Handle<SuperellipseUiMaterial>)>,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    let Some(selected) = interactions
+        .iter()
+        .find(|(_, interaction)| **interaction == Interaction::Pressed)
+        .map(|(entity, _)| entity)
+    else {
+        return;
+    };
+    let outline = |entity| {
+        if entity == selected {
+            selected_outline()
+        } else {
+            RoundUiOutline::default()
+        }
+    };
+
+    for (entity, handle) in cards.iter() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.outline = outline(entity);
+        }
+    }
+    for (entity, handle) in superellipse_cards.iter() {
+        if let Some(material) = superellipse_materials.get_mut(handle) {
+            material.outline = outline(entity);
+        }
+    }
+}
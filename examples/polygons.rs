@@ -0,0 +1,205 @@
+//! This example demonstrates regular polygons and stars drawn with the `PolygonUiMaterial`: a small hexagonal skill
+//! tree, with a skill that is being learned, and a star rating. Click a star to change the rating.
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, rate)
+        .run();
+}
+
+const HEX_SIZE: f32 = 80.0;
+const STAR_SIZE: f32 = 48.0;
+const STAR_COUNT: usize = 5;
+
+/// Component for a star of the rating, with its value
+#[derive(Component)]
+struct RatingStar(usize);
+
+/// Materials for the stars of the rating
+#[derive(Resource)]
+struct StarMaterials {
+    filled: Handle<PolygonUiMaterial>,
+    empty: Handle<PolygonUiMaterial>,
+}
+
+fn setup(mut commands: Commands, mut materials: ResMut<Assets<PolygonUiMaterial>>) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    // Flat-topped hexagons with rounded corners, for unlocked, learning and locked skills
+    let hexagon = PolygonUiMaterial {
+        points: 6,
+        rotation: PI / 6.,
+        corner_radius: 6.,
+        border_thickness: 4.,
+        ..default()
+    };
+    // Unlocked skills are raised, with a darker base showing below them
+    let unlocked = materials.add(PolygonUiMaterial {
+        background_color: Srgba::hex("#5cb3af").unwrap().into(),
+        border_color: Srgba::hex("#2c5f5c").unwrap().into(),
+        offset: RoundUiOffset::bottom(6.).into(),
+        shadow: RoundUiShadow::new(LinearRgba::new(0., 0., 0., 0.4), Vec2::new(0., 4.), 8.),
+        ..hexagon.clone()
+    });
+    let locked_hexagon = PolygonUiMaterial {
+        background_color: Srgba::hex("#2a2e37").unwrap().into(),
+        border_color: Srgba::hex("#4a505c").unwrap().into(),
+        border_style: RoundUiBorderStyle::dashed(8., 6.),
+        inset_shadow: RoundUiShadow::new(LinearRgba::new(0., 0., 0., 0.5), Vec2::new(0., 3.), 6.),
+        ..hexagon
+    };
+    // A skill that is being learned fills up from the bottom
+    let learning = materials.add(PolygonUiMaterial {
+        fill_color: Srgba::hex("#5cb3af").unwrap().with_alpha(0.6).into(),
+        fill_fraction: 0.6,
+        fill_direction: RoundUiFillDirection::BottomToTop,
+        ..locked_hexagon.clone()
+    });
+    let locked = materials.add(locked_hexagon);
+
+    // Five-pointed stars with rounded points, for the rating
+    let star = PolygonUiMaterial {
+        points: 5,
+        inner_radius: 0.45,
+        corner_radius: 3.,
+        border_thickness: 3.,
+        ..default()
+    };
+    let star_materials = StarMaterials {
+        filled: materials.add(PolygonUiMaterial {
+            background_color: Srgba::hex("#ffd84a").unwrap().into(),
+            border_color: Srgba::hex("#b8860b").unwrap().into(),
+            ..star.clone()
+        }),
+        empty: materials.add(PolygonUiMaterial {
+            background_color: LinearRgba::NONE,
+            border_color: Srgba::hex("#9aa3b5").unwrap().into(),
+            ..star
+        }),
+    };
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(60.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            // A skill tree of three columns of hexagons, with the middle column shifted down so they interlock
+            p.spawn(NodeBundle {
+                style: Style {
+                    column_gap: Val::Px(-HEX_SIZE * 0.2),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|p| {
+                for (column, unlocked_count) in [(0, 2), (1, 1), (2, 0)] {
+                    p.spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::top(Val::Px(if column == 1 {
+                                HEX_SIZE * 0.45
+                            } else {
+                                0.
+                            })),
+                            row_gap: Val::Px(-HEX_SIZE * 0.1),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|p| {
+                        for row in 0..2 {
+                            p.spawn(MaterialNodeBundle {
+                                material: if row < unlocked_count {
+                                    unlocked.clone()
+                                } else if row == unlocked_count && column == 1 {
+                                    learning.clone()
+                                } else {
+                                    locked.clone()
+                                },
+                                style: Style {
+                                    width: Val::Px(HEX_SIZE),
+                                    height: Val::Px(HEX_SIZE),
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        }
+                    });
+                }
+            });
+
+            // A star rating, with three of the five stars filled
+            p.spawn(NodeBundle {
+                style: Style {
+                    column_gap: Val::Px(8.),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|p| {
+                for i in 0..STAR_COUNT {
+                    p.spawn((
+                        MaterialNodeBundle {
+                            material: if i < 3 {
+                                star_materials.filled.clone()
+                            } else {
+                                star_materials.empty.clone()
+                            },
+                            style: Style {
+                                width: Val::Px(STAR_SIZE),
+                                height: Val::Px(STAR_SIZE),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        Interaction::default(),
+                        RatingStar(i + 1),
+                    ));
+                }
+            });
+        });
+
+    commands.insert_resource(star_materials);
+}
+
+/// Fills the stars up to the one that was clicked
+fn rate(
+    star_materials: Res<StarMaterials>,
+    interactions: Query<(&Interaction, &RatingStar), Changed<Interaction>>,
+    mut stars: Query<(&RatingStar, &mut Handle<PolygonUiMaterial>)>,
+) {
+    let Some(rating) = interactions
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, star)| star.0)
+    else {
+        return;
+    };
+
+    for (star, mut handle) in stars.iter_mut() {
+        *handle = if star.0 <= rating {
+            star_materials.filled.clone()
+        } else {
+            star_materials.empty.clone()
+        };
+    }
+}
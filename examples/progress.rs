@@ -0,0 +1,178 @@
+//! This example demonstrates progress bars drawn with the `fill_fraction`, `fill_direction` and `fill_color` properties
+//! of round rect and superellipse materials, animated between empty and full.
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (animate_bars, animate_superellipse_bars))
+        .run();
+}
+
+const BAR_WIDTH: f32 = 400.0;
+const BAR_HEIGHT: f32 = 32.0;
+
+/// Component for animating the fill of a progress bar
+#[derive(Component)]
+struct ProgressBar {
+    /// Offset of the animation, in seconds, so the bars don't move together
+    phase: f32,
+}
+
+impl ProgressBar {
+    /// The fill of the bar at the given time, going back and forth between empty and full
+    fn fraction(&self, seconds: f32) -> f32 {
+        0.5 - 0.5 * ((seconds + self.phase) * 0.8).cos()
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let track_color: LinearRgba = Srgba::hex("#2a2e37").unwrap().into();
+
+    // A health bar with a 3D offset
+    let health_bar = materials.add(RoundRectUiMaterial {
+        background_color: track_color,
+        border_color: Srgba::hex("#14161b").unwrap().into(),
+        border_radius: RoundUiBorder::all(BAR_HEIGHT).into(),
+        offset: RoundUiOffset::bottom(4.).into(),
+        fill_color: Srgba::hex("#F76161").unwrap().into(),
+        ..default()
+    });
+
+    // An XP meter inside a border, filling from the right
+    let xp_meter = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color: track_color,
+        border_color: Srgba::hex("#9aa3b5").unwrap().into(),
+        border_radius: RoundUiBorder::all(BAR_HEIGHT).into(),
+        border_thickness: RoundUiOffset::all(3.).into(),
+        fill_color: Srgba::hex("#5cb3af").unwrap().into(),
+        fill_direction: RoundUiFillDirection::RightToLeft,
+        ..default()
+    });
+
+    // A loading bar with an inset shadow drawn over the fill
+    let loading_bar = materials.add(RoundRectUiMaterial {
+        background_color: track_color,
+        border_radius: RoundUiBorder::all(12.).into(),
+        inset_shadow: RoundUiShadow::new(LinearRgba::new(0., 0., 0., 0.5), Vec2::new(0., 2.), 4.),
+        fill_color: Srgba::hex("#ffd84a").unwrap().into(),
+        ..default()
+    });
+
+    // A vertical gauge, filling from the bottom
+    let gauge = materials.add(RoundRectUiMaterial {
+        background_color: track_color,
+        border_color: Srgba::hex("#14161b").unwrap().into(),
+        border_radius: RoundUiBorder::all(BAR_HEIGHT).into(),
+        offset: RoundUiOffset::all(3.).into(),
+        fill_color: Srgba::hex("#2f6fe4").unwrap().into(),
+        fill_direction: RoundUiFillDirection::BottomToTop,
+        ..default()
+    });
+
+    // Spawn the horizontal bars in a column, next to the gauge
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(60.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            p.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(40.),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|p| {
+                let style = Style {
+                    width: Val::Px(BAR_WIDTH),
+                    height: Val::Px(BAR_HEIGHT),
+                    ..default()
+                };
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: health_bar,
+                        style: style.clone(),
+                        ..default()
+                    },
+                    ProgressBar { phase: 0. },
+                ));
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: xp_meter,
+                        style: style.clone(),
+                        ..default()
+                    },
+                    ProgressBar { phase: 1. },
+                ));
+                p.spawn((
+                    MaterialNodeBundle {
+                        material: loading_bar,
+                        style: style.clone(),
+                        ..default()
+                    },
+                    ProgressBar { phase: 2. },
+                ));
+            });
+
+            p.spawn((
+                MaterialNodeBundle {
+                    material: gauge,
+                    style: Style {
+                        width: Val::Px(BAR_HEIGHT),
+                        height: Val::Px(200.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ProgressBar { phase: 3. },
+            ));
+        });
+}
+
+/// Moves the fill of the round rect progress bars back and forth
+fn animate_bars(
+    time: Res<Time>,
+    query: Query<(&ProgressBar, &Handle<RoundRectUiMaterial>)>,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+) {
+    for (bar, handle) in query.iter() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.fill_fraction = bar.fraction(time.elapsed_seconds());
+        }
+    }
+}
+
+/// Moves the fill of the superellipse progress bars back and forth
+fn animate_superellipse_bars(
+    time: Res<Time>,
+    query: Query<(&ProgressBar, &Handle<SuperellipseUiMaterial>)>,
+    mut materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    for (bar, handle) in query.iter() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.fill_fraction = bar.fraction(time.elapsed_seconds());
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! This example demonstrates border radii and offsets that scale with the size of the node and the window.
+//!
+//! Resize the window to see the shapes adjust.
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Vh(5.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            // A panel whose corners and border scale with the window
+            p.spawn((
+                MaterialNodeBundle {
+                    material: superellipse_materials.add(SuperellipseUiMaterial {
+                        background_color: Srgba::hex("#5cb3af").unwrap().into(),
+                        border_color: LinearRgba::WHITE,
+                        ..default()
+                    }),
+                    style: Style {
+                        width: Val::Vw(60.),
+                        height: Val::Vh(40.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                RoundUiResponsive {
+                    border_radius: Some(RoundUiBorderVal::all(RoundUiVal::VMin(8.))),
+                    offset: None,
+                    border_thickness: Some(RoundUiOffsetVal::all(RoundUiVal::VMin(1.))),
+                },
+            ));
+
+            // A button that stays pill-shaped, with a 3D offset that is a percentage of its height
+            p.spawn((
+                MaterialNodeBundle {
+                    material: materials.add(RoundRectUiMaterial {
+                        background_color: Srgba::hex("#F76161").unwrap().into(),
+                        border_color: Srgba::hex("#A53A3D").unwrap().into(),
+                        ..default()
+                    }),
+                    style: Style {
+                        width: Val::Vw(30.),
+                        height: Val::Vh(12.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                RoundUiResponsive {
+                    border_radius: Some(RoundUiBorderVal::all(RoundUiVal::Percent(50.))),
+                    offset: Some(RoundUiOffsetVal {
+                        bottom: RoundUiVal::PercentHeight(10.),
+                        ..default()
+                    }),
+                    border_thickness: None,
+                },
+            ));
+        });
+}
@@ -0,0 +1,108 @@
+//! This example demonstrates drop shadows and inset shadows on round rect and superellipse material nodes.
+
+use bevy::prelude::*;
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyRoundUiDefaultPlugins))
+        .insert_resource(ClearColor(Srgba::hex("#e8e4dc").unwrap().into()))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+const SHAPE_SIZE: f32 = 160.0;
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut superellipse_materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    let background_color: LinearRgba = Srgba::hex("#F76161").unwrap().into();
+    let shadow_color = LinearRgba::new(0., 0., 0., 0.35);
+
+    // A soft shadow below a round rect
+    let soft_shadow = materials.add(RoundRectUiMaterial {
+        background_color,
+        border_radius: RoundUiBorder::all(40.0).into(),
+        shadow: RoundUiShadow::new(shadow_color, Vec2::new(0., 10.), 24.),
+        ..default()
+    });
+
+    // A hard shadow offset to the bottom right, following the 3D offset
+    let hard_shadow = materials.add(RoundRectUiMaterial {
+        background_color,
+        border_color: Srgba::hex("#A53A3D").unwrap().into(),
+        border_radius: RoundUiBorder::all(40.0).into(),
+        offset: RoundUiOffset::bottom(10.0).into(),
+        shadow: RoundUiShadow::new(shadow_color, Vec2::new(8., 8.), 0.),
+        ..default()
+    });
+
+    // A glow with spread around a superellipse
+    let glow = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color,
+        border_radius: RoundUiBorder::all(SHAPE_SIZE).into(),
+        shadow: RoundUiShadow::new(background_color, Vec2::ZERO, 16.).with_spread(4.),
+        ..default()
+    });
+
+    // A recessed field with an inset shadow
+    let recessed = materials.add(RoundRectUiMaterial {
+        background_color: Srgba::hex("#f5f2ec").unwrap().into(),
+        border_radius: RoundUiBorder::all(40.0).into(),
+        inset_shadow: RoundUiShadow::new(shadow_color, Vec2::new(0., 6.), 12.),
+        ..default()
+    });
+
+    // An inset shadow inside the border of a superellipse
+    let recessed_superellipse = superellipse_materials.add(SuperellipseUiMaterial {
+        background_color,
+        border_color: LinearRgba::WHITE,
+        border_radius: RoundUiBorder::all(SHAPE_SIZE).into(),
+        border_thickness: RoundUiOffset::all(6.).into(),
+        inset_shadow: RoundUiShadow::new(shadow_color, Vec2::new(4., 4.), 8.).with_spread(2.),
+        ..default()
+    });
+
+    // Spawn the nodes in a row in the middle of the screen
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(60.),
+                row_gap: Val::Px(60.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            let style = Style {
+                width: Val::Px(SHAPE_SIZE),
+                height: Val::Px(SHAPE_SIZE),
+                ..default()
+            };
+            for material in [soft_shadow, hard_shadow, recessed] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+            for material in [glow, recessed_superellipse] {
+                p.spawn(MaterialNodeBundle {
+                    material,
+                    style: style.clone(),
+                    ..default()
+                });
+            }
+        });
+}
@@ -1,5 +1,5 @@
-//! This example demonstrates different shapes that can be achieved by updating the [`RoundUiMaterial::border_radius`]
-//! property.
+//! This example demonstrates different shapes that can be achieved by updating the [`RoundUiMaterial::border_radius`],
+//! [`RoundUiMaterial::corners`] and [`RoundUiMaterial::corner_smoothing`] properties.
 
 use bevy::prelude::*;
 
@@ -32,6 +32,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#A53A3D").unwrap().into(),
             border_radius: RoundUiBorder::all(20.0).into(),
             offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
         }),
         // Round rect with border
         materials.add(RoundRectUiMaterial {
@@ -39,6 +40,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#FFFFFF").unwrap().into(),
             border_radius: RoundUiBorder::all(20.0).into(),
             offset: RoundUiOffset::all(4.0).into(),
+            ..default()
         }),
         // Round rect with offset to bottom right
         materials.add(RoundRectUiMaterial {
@@ -46,6 +48,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#A53A3D").unwrap().into(),
             border_radius: RoundUiBorder::all(20.0).into(),
             offset: RoundUiOffset::bottom_right(5.0).into(),
+            ..default()
         }),
     ];
     let circle_materials = [
@@ -61,6 +64,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#A53A3D").unwrap().into(),
             border_radius: RoundUiBorder::all(SHAPE_SIZE).into(),
             offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
         }),
         // Circle with border
         // NOTE: The border is not perfect
@@ -69,6 +73,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#FFFFFF").unwrap().into(),
             border_radius: RoundUiBorder::all(SHAPE_SIZE).into(),
             offset: RoundUiOffset::all(4.0).into(),
+            ..default()
         }),
         // Circle with border-radius longer than sides
         materials.add(RoundRectUiMaterial {
@@ -76,10 +81,58 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             border_color: Srgba::hex("#A53A3D").unwrap().into(),
             border_radius: RoundUiBorder::all(SHAPE_SIZE * 2.).into(),
             offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
         }),
     ];
+    let corner_materials = [
+        // Chamfered corners
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#F76161").unwrap().into(),
+            border_color: Srgba::hex("#A53A3D").unwrap().into(),
+            border_radius: RoundUiBorder::all(40.0).into(),
+            corners: RoundUiCorners::all(RoundUiCornerType::Chamfer),
+            offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
+        }),
+        // Notched corners with a border
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#F76161").unwrap().into(),
+            border_color: Srgba::hex("#FFFFFF").unwrap().into(),
+            border_radius: RoundUiBorder::all(30.0).into(),
+            corners: RoundUiCorners::all(RoundUiCornerType::Notch),
+            offset: RoundUiOffset::all(4.0).into(),
+            ..default()
+        }),
+        // Scooped corners
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#F76161").unwrap().into(),
+            border_radius: RoundUiBorder::all(40.0).into(),
+            corners: RoundUiCorners::all(RoundUiCornerType::Scoop),
+            ..default()
+        }),
+        // A chamfered top left corner, with the other corners rounded
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#F76161").unwrap().into(),
+            border_color: Srgba::hex("#A53A3D").unwrap().into(),
+            border_radius: RoundUiBorder::all(40.0).into(),
+            corners: RoundUiCorners::top_left(RoundUiCornerType::Chamfer),
+            offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
+        }),
+    ];
+    // The same corner radius with increasing Figma-style corner smoothing
+    let smoothing_materials = [0.0, 0.3, 0.6, 1.0].map(|corner_smoothing| {
+        materials.add(RoundRectUiMaterial {
+            background_color: Srgba::hex("#F76161").unwrap().into(),
+            border_color: Srgba::hex("#A53A3D").unwrap().into(),
+            border_radius: RoundUiBorder::all(40.0).into(),
+            corner_smoothing,
+            offset: RoundUiOffset::bottom(10.0).into(),
+            ..default()
+        })
+    });
 
-    // Spawn two rows of material nodes in the center of the screen
+    // Spawn four rows of material nodes in the center of the screen
     commands
         .spawn(NodeBundle {
             style: Style {
@@ -93,7 +146,12 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMateria
             ..default()
         })
         .with_children(|p| {
-            for row in [rect_materials.iter(), circle_materials.iter()] {
+            for row in [
+                rect_materials.iter(),
+                circle_materials.iter(),
+                corner_materials.iter(),
+                smoothing_materials.iter(),
+            ] {
                 p.spawn(NodeBundle {
                     style: Style {
                         width: Val::Percent(100.0),
@@ -0,0 +1,208 @@
+//! This example stress tests a grid of 2,000 differently colored rounded rects, drawn either with a material per node
+//! or with `RoundRectUiInstance`.
+//!
+//! Press Space to switch between the two, and compare the frame times shown on screen and logged to the console.
+
+use bevy::{
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    prelude::*,
+    window::PresentMode,
+};
+
+use bevy_round_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    // Don't wait for vsync, so the frame times show the cost of rendering
+                    present_mode: PresentMode::AutoNoVsync,
+                    ..default()
+                }),
+                ..default()
+            }),
+            BevyRoundUiDefaultPlugins,
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+        ))
+        .insert_resource(ClearColor(Srgba::hex("#20232a").unwrap().into()))
+        .init_resource::<RenderMode>()
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (
+                toggle_render_mode,
+                spawn_grid.run_if(resource_changed::<RenderMode>),
+                update_stats_text,
+            ),
+        )
+        .run();
+}
+
+const COLUMNS: usize = 50;
+const ROWS: usize = 40;
+const CELL_SIZE: f32 = 16.0;
+const CELL_GAP: f32 = 4.0;
+
+/// How the cells of the grid are drawn
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum RenderMode {
+    /// Each cell has a `RoundRectUiMaterial` of its own, and is drawn with a separate draw call
+    #[default]
+    Materials,
+    /// Each cell has a `RoundRectUiInstance`, and consecutive cells are drawn together
+    Instanced,
+}
+
+/// Marker for the node containing the grid
+#[derive(Component)]
+struct Grid;
+
+/// Marker for the text showing the render mode and frame times
+#[derive(Component)]
+struct StatsText;
+
+fn setup(mut commands: Commands) {
+    // Camera so we can see UI
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|p| {
+            p.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 20.,
+                        ..default()
+                    },
+                ),
+                StatsText,
+            ));
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::Grid,
+                        grid_template_columns: RepeatedGridTrack::px(COLUMNS as u16, CELL_SIZE),
+                        grid_auto_rows: vec![GridTrack::px(CELL_SIZE)],
+                        row_gap: Val::Px(CELL_GAP),
+                        column_gap: Val::Px(CELL_GAP),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Grid,
+            ));
+        });
+}
+
+fn toggle_render_mode(keys: Res<ButtonInput<KeyCode>>, mut mode: ResMut<RenderMode>) {
+    if keys.just_pressed(KeyCode::Space) {
+        *mode = match *mode {
+            RenderMode::Materials => RenderMode::Instanced,
+            RenderMode::Instanced => RenderMode::Materials,
+        };
+    }
+}
+
+/// The color of the cell in the given column and row
+fn cell_color(column: usize, row: usize) -> LinearRgba {
+    let hue = 360. * column as f32 / COLUMNS as f32;
+    let lightness = 0.35 + 0.4 * row as f32 / ROWS as f32;
+    Hsla::hsl(hue, 0.7, lightness).into()
+}
+
+/// Replaces the cells of the grid with cells drawn in the current render mode
+fn spawn_grid(
+    mut commands: Commands,
+    mode: Res<RenderMode>,
+    grid: Query<Entity, With<Grid>>,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+) {
+    let Ok(grid) = grid.get_single() else {
+        return;
+    };
+    commands.entity(grid).despawn_descendants();
+
+    let style = Style {
+        width: Val::Px(CELL_SIZE),
+        height: Val::Px(CELL_SIZE),
+        ..default()
+    };
+    let border_radius = RoundUiBorder::all(5.);
+    let offset = RoundUiOffset::bottom(2.);
+    let border_color = LinearRgba::new(0., 0., 0., 0.4);
+
+    commands.entity(grid).with_children(|p| {
+        for row in 0..ROWS {
+            for column in 0..COLUMNS {
+                let background_color = cell_color(column, row);
+                match *mode {
+                    RenderMode::Materials => {
+                        p.spawn(MaterialNodeBundle {
+                            material: materials.add(RoundRectUiMaterial {
+                                background_color,
+                                border_color,
+                                border_radius: border_radius.into(),
+                                offset: offset.into(),
+                                ..default()
+                            }),
+                            style: style.clone(),
+                            ..default()
+                        });
+                    }
+                    RenderMode::Instanced => {
+                        p.spawn((
+                            NodeBundle {
+                                style: style.clone(),
+                                ..default()
+                            },
+                            RoundRectUiInstance {
+                                background_color,
+                                border_color,
+                                border_radius,
+                                offset,
+                                ..default()
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+    });
+}
+
+fn update_stats_text(
+    diagnostics: Res<DiagnosticsStore>,
+    mode: Res<RenderMode>,
+    mut query: Query<&mut Text, With<StatsText>>,
+) {
+    let frame_time = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|diagnostic| diagnostic.smoothed())
+        .unwrap_or(0.);
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|diagnostic| diagnostic.smoothed())
+        .unwrap_or(0.);
+
+    for mut text in query.iter_mut() {
+        text.sections[0].value = format!(
+            "{} cells, {:?} (press Space to switch): {frame_time:.2} ms, {fps:.0} fps",
+            COLUMNS * ROWS,
+            *mode,
+        );
+    }
+}
@@ -16,6 +16,28 @@ const PANEL_WIDTH: f32 = 400.0;
 const PANEL_HEIGHT: f32 = 200.0;
 const BORDER_THICKNESS: f32 = 4.;
 
+/// The thickness of each side of the border
+#[derive(Component)]
+pub enum BorderSides {
+    Even,
+    Bottom,
+    Underline,
+}
+
+impl BorderSides {
+    fn thickness(&self) -> Vec4 {
+        match self {
+            BorderSides::Even => RoundUiOffset::all(BORDER_THICKNESS),
+            BorderSides::Bottom => RoundUiOffset {
+                bottom: BORDER_THICKNESS * 4.,
+                ..RoundUiOffset::all(BORDER_THICKNESS)
+            },
+            BorderSides::Underline => RoundUiOffset::bottom(BORDER_THICKNESS),
+        }
+        .into()
+    }
+}
+
 #[derive(Component)]
 pub enum PanelSize {
     Short,
@@ -36,7 +58,8 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<SuperellipseUiMate
         background_color,
         border_color,
         border_radius,
-        border_thickness: BORDER_THICKNESS,
+        border_thickness: BorderSides::Even.thickness(),
+        ..default()
     });
 
     // Spawn help text
@@ -51,6 +74,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<SuperellipseUiMate
         })
         .with_children(|p| {
             help_text(p, "Toggle Height:      SPACE");
+            help_text(p, "Toggle Border:      B");
         });
 
     // Spawn the material in the middle of the screen
@@ -68,6 +92,7 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<SuperellipseUiMate
         .with_children(|p| {
             p.spawn((
                 PanelSize::Short,
+                BorderSides::Even,
                 MaterialNodeBundle {
                     material: panel_material_superellipse,
                     style: Style {
@@ -91,9 +116,30 @@ fn help_text(p: &mut ChildBuilder, val: impl Into<String>) {
     ));
 }
 
-fn handle_keys(keys: Res<ButtonInput<KeyCode>>, mut query: Query<(&mut PanelSize, &mut Style)>) {
+fn handle_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(
+        &mut PanelSize,
+        &mut BorderSides,
+        &mut Style,
+        &Handle<SuperellipseUiMaterial>,
+    )>,
+    mut materials: ResMut<Assets<SuperellipseUiMaterial>>,
+) {
+    if keys.just_pressed(KeyCode::KeyB) {
+        for (_, mut border_sides, _, handle) in query.iter_mut() {
+            *border_sides = match *border_sides {
+                BorderSides::Even => BorderSides::Bottom,
+                BorderSides::Bottom => BorderSides::Underline,
+                BorderSides::Underline => BorderSides::Even,
+            };
+            if let Some(material) = materials.get_mut(handle) {
+                material.border_thickness = border_sides.thickness();
+            }
+        }
+    }
     if keys.just_pressed(KeyCode::Space) {
-        for (mut panel_size, mut style) in query.iter_mut() {
+        for (mut panel_size, _, mut style, _) in query.iter_mut() {
             *panel_size = match *panel_size {
                 PanelSize::Short => PanelSize::Square,
                 PanelSize::Square => PanelSize::Long,
@@ -35,7 +35,8 @@ fn setup(mut commands: Commands, mut materials: ResMut<Assets<SuperellipseUiMate
         background_color,
         border_color,
         border_radius,
-        border_thickness: BORDER_THICKNESS,
+        border_thickness: RoundUiOffset::all(BORDER_THICKNESS).into(),
+        ..default()
     });
 
     // Spawn 2 colored columns so we can see the transparency of the material
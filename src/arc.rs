@@ -0,0 +1,156 @@
+use std::f32::consts::TAU;
+
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    render::{render_asset::RenderAssets, render_resource::*, texture::GpuImage},
+};
+
+use crate::{
+    interaction::add_interaction_systems,
+    shared::{configure_system_sets, load_shared_shaders},
+    transition::{add_transition_systems, mix_color, mix_f32, RoundUiTransitionMaterial},
+    types::*,
+};
+
+pub const ARC_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(51930472861520394871);
+
+/// Plugin which adds an `ArcUiMaterial` to the app.
+pub struct ArcMaterialPlugin;
+
+impl Plugin for ArcMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        load_shared_shaders(app);
+        configure_system_sets(app);
+        load_internal_asset!(app, ARC_SHADER_HANDLE, "arc.wgsl", Shader::from_wgsl);
+
+        app.add_plugins(UiMaterialPlugin::<ArcUiMaterial>::default());
+        add_interaction_systems::<ArcUiMaterial>(app);
+        add_transition_systems::<ArcUiMaterial>(app);
+    }
+}
+
+/// UI Material that renders a ring, with an arc of it drawn in a different color, e.g. for cooldown rings, radial
+/// timers and loading spinners.
+///
+/// The ring fills the largest circle that fits in the node. Angles are in radians where, as in CSS, `0.0` points up and
+/// positive angles rotate clockwise.
+///
+/// # Example:
+///
+/// ```
+/// use std::f32::consts::TAU;
+///
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// // A radial timer that is three quarters full
+/// let material = ArcUiMaterial {
+///     track_color: LinearRgba::new(1., 1., 1., 0.2),
+///     fill_color: LinearRgba::WHITE,
+///     sweep: 0.75 * TAU,
+///     thickness: 6.,
+///     ..default()
+/// };
+/// assert_eq!(material.cap, RoundUiArcCap::Round);
+/// ```
+#[derive(AsBindGroup, Asset, Debug, Clone, Reflect)]
+#[uniform(0, ArcUiMaterialUniform)]
+#[reflect(Default, Debug)]
+pub struct ArcUiMaterial {
+    /// The color of the whole ring, drawn behind the arc
+    pub track_color: LinearRgba,
+
+    /// The color of the arc
+    pub fill_color: LinearRgba,
+
+    /// The angle the arc starts at, in radians
+    pub start_angle: f32,
+
+    /// The angle covered by the arc, in radians, from `0.0` (no arc) to `TAU` (the whole ring). Negative values cover
+    /// the angle counterclockwise from `start_angle`.
+    pub sweep: f32,
+
+    /// The thickness of the ring in pixels. It is clamped to the radius of the ring, so large values give a pie.
+    pub thickness: f32,
+
+    /// The shape of the ends of the arc
+    pub cap: RoundUiArcCap,
+
+    /// Width of the anti-aliased edges of the shape, in pixels. See [edge softness](crate#edge-softness).
+    pub edge_softness: f32,
+}
+
+impl Default for ArcUiMaterial {
+    fn default() -> Self {
+        Self {
+            track_color: LinearRgba::NONE,
+            fill_color: LinearRgba::WHITE,
+            start_angle: 0.,
+            sweep: TAU,
+            thickness: 8.,
+            cap: RoundUiArcCap::default(),
+            edge_softness: 0.,
+        }
+    }
+}
+
+pub use shader_types::ArcUiMaterialUniform;
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// The GPU representation of an `ArcUiMaterial`.
+    #[derive(Clone, Default, ShaderType)]
+    pub struct ArcUiMaterialUniform {
+        pub(super) track_color: LinearRgba,
+        pub(super) fill_color: LinearRgba,
+        pub(super) start_angle: f32,
+        pub(super) sweep: f32,
+        pub(super) thickness: f32,
+        pub(super) edge_softness: f32,
+        pub(super) cap: u32,
+    }
+}
+
+impl AsBindGroupShaderType<ArcUiMaterialUniform> for ArcUiMaterial {
+    fn as_bind_group_shader_type(&self, _images: &RenderAssets<GpuImage>) -> ArcUiMaterialUniform {
+        // the shader only draws clockwise arcs, so counterclockwise arcs start at their other end
+        let (start_angle, sweep) = if self.sweep < 0. {
+            (self.start_angle + self.sweep, -self.sweep)
+        } else {
+            (self.start_angle, self.sweep)
+        };
+        ArcUiMaterialUniform {
+            track_color: self.track_color,
+            fill_color: self.fill_color,
+            start_angle,
+            sweep,
+            thickness: self.thickness,
+            edge_softness: self.edge_softness,
+            cap: self.cap.into(),
+        }
+    }
+}
+
+impl UiMaterial for ArcUiMaterial {
+    fn fragment_shader() -> ShaderRef {
+        ARC_SHADER_HANDLE.into()
+    }
+}
+
+impl RoundUiTransitionMaterial for ArcUiMaterial {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            track_color: mix_color(self.track_color, target.track_color, t),
+            fill_color: mix_color(self.fill_color, target.fill_color, t),
+            start_angle: mix_f32(self.start_angle, target.start_angle, t),
+            sweep: mix_f32(self.sweep, target.sweep, t),
+            thickness: mix_f32(self.thickness, target.thickness, t),
+            edge_softness: mix_f32(self.edge_softness, target.edge_softness, t),
+            ..target.clone()
+        }
+    }
+}
@@ -0,0 +1,100 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+#import bevy_render::maths::PI_2
+#import bevy_round_ui::shared::{
+    blend_over,
+    coverage,
+    edge_width,
+    premultiply,
+    unpremultiply,
+}
+
+struct ArcUiMaterial {
+    /// Color of the whole ring, drawn behind the arc
+    @location(0) track_color: vec4<f32>,
+    /// Color of the arc
+    @location(1) fill_color: vec4<f32>,
+    /// Angle the arc starts at, in radians, where 0 points up and positive
+    /// angles rotate clockwise
+    @location(2) start_angle: f32,
+    /// Angle covered by the arc in radians, from 0 to 2π
+    @location(3) sweep: f32,
+    /// Thickness of the ring in pixels
+    @location(4) thickness: f32,
+    /// Width of the anti-aliased edge, in pixels
+    @location(5) edge_softness: f32,
+    /// Shape of the ends of the arc, matching `RoundUiArcCap`:
+    /// 0: round, 1: flat
+    @location(6) cap: u32,
+}
+
+@group(1) @binding(0)
+var<uniform> input: ArcUiMaterial;
+
+/// Signed distance from `p` to a ring whose center line has radius `r`, with
+/// half thickness `h`.
+fn sd_ring(p: vec2f, r: f32, h: f32) -> f32 {
+    return abs(length(p) - r) - h;
+}
+
+/// Signed distance from `p` to an arc of a ring, centered on the positive y
+/// axis and reaching `aperture` radians to each side of it.
+///
+/// Based on the arc distance function by Inigo Quilez, with either round caps
+/// or flat caps cut along the radius.
+fn sd_arc(p: vec2f, aperture: f32, r: f32, h: f32, flat_caps: bool) -> f32 {
+    // direction of the end of the arc
+    let end = vec2f(sin(aperture), cos(aperture));
+    let q = vec2f(abs(p.x), p.y);
+    // positive when `q` is inside the angle covered by the arc
+    let inside = end.x * q.y - end.y * q.x;
+
+    if !flat_caps {
+        if inside >= 0. {
+            return sd_ring(q, r, h);
+        }
+        return length(q - end * r) - h;
+    }
+
+    if inside >= 0. {
+        // the cap is the closest edge near the end of the arc
+        if dot(q, end) > 0. {
+            return max(sd_ring(q, r, h), -inside);
+        }
+        return sd_ring(q, r, h);
+    }
+    // outside the arc, the closest point is on the cap
+    let along = clamp(dot(q, end), r - h, r + h);
+    return length(q - end * along);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // adjust UVs around the middle of the node, in pixels
+    let p = (in.uv - 0.5) * in.size;
+
+    // the ring fills the largest circle that fits in the node
+    let outer = 0.5 * min(in.size.x, in.size.y);
+    let h = 0.5 * clamp(input.thickness, 0., outer);
+    let r = outer - h;
+
+    let d_track = sd_ring(p, r, h);
+
+    let aa = edge_width(d_track, input.edge_softness);
+
+    var color = premultiply(input.track_color) * coverage(d_track, aa);
+    if input.sweep > 0. {
+        // rotate so the middle of the arc points along the positive y axis,
+        // flipping y so it points up
+        let aperture = 0.5 * min(input.sweep, PI_2);
+        let middle = input.start_angle + aperture;
+        let up = vec2f(p.x, -p.y);
+        let q = vec2f(
+            up.x * cos(middle) - up.y * sin(middle),
+            up.x * sin(middle) + up.y * cos(middle),
+        );
+        let d_fill = sd_arc(q, aperture, r, h, input.cap == 1u);
+        color = blend_over(premultiply(input.fill_color) * coverage(d_fill, aa), color);
+    }
+
+    return unpremultiply(color);
+}
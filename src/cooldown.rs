@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use crate::types::*;
+
+/// Drives the `sweep_mask` of a material node from a `Timer`, e.g. for the cooldown of an ability button.
+///
+/// The timer is advanced every frame, and the fraction of the sweep mask is set to the part of the timer that remains,
+/// so the overlay covers the whole shape when the timer starts and sweeps away as it elapses. The color, start angle
+/// and direction of the sweep mask are taken from the material.
+///
+/// Nodes with this component should each have their own material, since nodes sharing a material would overwrite
+/// each other's values.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn use_ability(mut query: Query<&mut RoundUiCooldown>) {
+///     for mut cooldown in query.iter_mut() {
+///         if cooldown.is_ready() {
+///             cooldown.restart();
+///         }
+///     }
+/// }
+///
+/// let cooldown = RoundUiCooldown::new(2.5);
+/// assert!(!cooldown.is_ready());
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+pub struct RoundUiCooldown {
+    /// The timer driving the sweep mask. Pausing it pauses the sweep.
+    pub timer: Timer,
+}
+
+impl RoundUiCooldown {
+    /// Creates a cooldown lasting `seconds`, starting immediately.
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+
+    /// Starts the cooldown again from the beginning.
+    pub fn restart(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Whether the cooldown has elapsed.
+    pub fn is_ready(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// The part of the cooldown that remains, from `1.0` when it starts to `0.0` once it has elapsed.
+    pub fn remaining(&self) -> f32 {
+        self.timer.fraction_remaining()
+    }
+}
+
+/// A material with a `RoundUiSweepMask` that a `RoundUiCooldown` can drive.
+pub(crate) trait RoundUiSweepMaskMaterial: UiMaterial {
+    fn sweep_mask(&self) -> RoundUiSweepMask;
+
+    fn set_sweep_mask(&mut self, sweep_mask: RoundUiSweepMask);
+}
+
+/// Adds the system driving the sweep masks of `RoundUiCooldown` nodes with the material `M`.
+pub(crate) fn add_cooldown_systems<M: RoundUiSweepMaskMaterial>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        update_cooldowns::<M>.in_set(RoundUiSystem::Cooldown),
+    );
+}
+
+/// Advances the cooldown timers, and writes the remaining fraction to the material of each node.
+///
+/// Materials are only modified when the fraction changes, so they are not re-uploaded to the GPU once the cooldown
+/// has elapsed.
+fn update_cooldowns<M: RoundUiSweepMaskMaterial>(
+    time: Res<Time>,
+    mut query: Query<(&mut RoundUiCooldown, &Handle<M>)>,
+    mut materials: ResMut<Assets<M>>,
+) {
+    for (mut cooldown, handle) in query.iter_mut() {
+        cooldown.timer.tick(time.delta());
+        let fraction = cooldown.remaining();
+
+        let Some(sweep_mask) = materials.get(handle).map(|material| material.sweep_mask()) else {
+            continue;
+        };
+        if sweep_mask.fraction == fraction {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(handle) {
+            material.set_sweep_mask(RoundUiSweepMask {
+                fraction,
+                ..sweep_mask
+            });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "round_rect"))]
+mod test {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{round_rect::RoundRectUiMaterial, test_util::*};
+
+    #[test]
+    fn cooldown_sweeps_away_and_restarts() {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )))
+        .add_systems(Update, update_cooldowns::<RoundRectUiMaterial>);
+
+        let handle = add_material(
+            &mut app,
+            RoundRectUiMaterial {
+                sweep_mask: RoundUiSweepMask::new(LinearRgba::BLACK, 0.),
+                ..default()
+            },
+        );
+        let entity = app
+            .world_mut()
+            .spawn((handle.clone(), RoundUiCooldown::new(1.)))
+            .id();
+        let fraction = |app: &App| material(app, &handle).sweep_mask.fraction;
+
+        app.update();
+        app.update();
+        let partial = fraction(&app);
+        assert!(partial > 0. && partial < 1., "fraction: {partial}");
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(fraction(&app), 0.);
+        assert!(app
+            .world()
+            .get::<RoundUiCooldown>(entity)
+            .unwrap()
+            .is_ready());
+
+        app.world_mut()
+            .get_mut::<RoundUiCooldown>(entity)
+            .unwrap()
+            .restart();
+        app.update();
+        assert!(fraction(&app) >= partial);
+    }
+}
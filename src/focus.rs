@@ -0,0 +1,545 @@
+use bevy::{prelude::*, transform::TransformSystem, ui::UiSystem};
+
+use crate::{
+    interaction::{RoundUiDisabled, RoundUiFocused},
+    round_rect::RoundRectUiMaterial,
+    types::*,
+};
+
+/// Plugin which moves focus between `RoundUiFocusable` nodes with the keyboard and gamepad, and draws a focus ring
+/// around the focused node.
+///
+/// - The arrow keys and the gamepad D-pad move focus to the nearest node in that direction.
+/// - Tab and Shift+Tab move focus to the next and previous node, in reading order.
+/// - Enter and the gamepad South button press the focused node: its `Interaction` is set to `Interaction::Pressed`
+///   while the button is held, just like a mouse click.
+///
+/// The focused node is given the `RoundUiFocused` marker, so it is shown with `RoundUiInteractionStyle::focused`.
+/// The ring is drawn with the `RoundUiFocusRing` resource, and follows the corner radii of the focused node's material.
+///
+/// This plugin isn't part of `BevyRoundUiDefaultPlugins`, as it uses the arrow keys and D-pad.
+pub struct RoundUiFocusPlugin;
+
+impl Plugin for RoundUiFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoundUiFocus>()
+            .init_resource::<RoundUiFocusRing>()
+            .init_resource::<RoundUiFocusRingState>()
+            .add_systems(
+                PreUpdate,
+                (navigate_focus, press_focused, sync_focused_marker)
+                    .chain()
+                    .after(UiSystem::Focus),
+            )
+            .add_systems(
+                PostUpdate,
+                animate_focus_ring
+                    .after(RoundUiFocusRingSystem)
+                    .after(TransformSystem::TransformPropagate),
+            );
+        add_focus_ring_systems::<RoundRectUiMaterial>(app);
+        #[cfg(feature = "superellipse")]
+        add_focus_ring_systems::<crate::superellipse::SuperellipseUiMaterial>(app);
+    }
+}
+
+/// Marker component for nodes that can be focused with the keyboard or a gamepad, with `RoundUiFocusPlugin`.
+///
+/// Nodes that are disabled with `RoundUiDisabled` or hidden are skipped.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiFocusable;
+
+/// The node that has keyboard and gamepad focus. Set `entity` to move focus from code.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn focus_first_button(
+///     mut focus: ResMut<RoundUiFocus>,
+///     buttons: Query<Entity, Added<RoundUiFocusable>>,
+/// ) {
+///     if let Some(entity) = buttons.iter().next() {
+///         focus.entity = Some(entity);
+///     }
+/// }
+/// ```
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoundUiFocus {
+    pub entity: Option<Entity>,
+}
+
+/// How the focus ring is drawn.
+#[derive(Resource, Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct RoundUiFocusRing {
+    /// The color of the ring
+    pub color: LinearRgba,
+    /// The width of the ring in pixels
+    pub width: f32,
+    /// The gap in pixels between the focused node and the ring
+    pub offset: f32,
+    /// How quickly the ring moves to a newly focused node and fades in or out. Higher is faster.
+    pub speed: f32,
+}
+
+impl Default for RoundUiFocusRing {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::WHITE,
+            width: 3.,
+            offset: 3.,
+            speed: 20.,
+        }
+    }
+}
+
+/// A material whose corner radii the focus ring can follow.
+pub(crate) trait RoundUiFocusRingMaterial: UiMaterial {
+    /// The corner radii of the material, in the units of `RoundRectUiMaterial::border_radius`
+    fn focus_ring_radius(&self) -> Vec4;
+}
+
+/// Systems finding the corner radii of the focused node.
+#[derive(SystemSet, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct RoundUiFocusRingSystem;
+
+/// Adds the system that makes the focus ring follow the corner radii of nodes with the material `M`.
+pub(crate) fn add_focus_ring_systems<M: RoundUiFocusRingMaterial>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        focus_ring_radius::<M>.in_set(RoundUiFocusRingSystem),
+    );
+}
+
+/// The current and target shape of the focus ring.
+#[derive(Resource, Default)]
+struct RoundUiFocusRingState {
+    /// The ring node and its material, spawned on first use
+    ring: Option<(Entity, Handle<RoundRectUiMaterial>)>,
+    rect: Rect,
+    radius: Vec4,
+    target_radius: Vec4,
+    alpha: f32,
+}
+
+/// A request to move focus.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum RoundUiNavigation {
+    Next,
+    Previous,
+    /// Towards the nearest node in a direction, where positive y is down
+    Direction(Vec2),
+}
+
+/// Keys that press the focused node, along with the gamepad South button.
+const PRESS_KEYS: [KeyCode; 2] = [KeyCode::Enter, KeyCode::NumpadEnter];
+
+/// The navigation requested by this frame's input, if any.
+fn navigation_input(
+    keys: &ButtonInput<KeyCode>,
+    gamepad_buttons: &ButtonInput<GamepadButton>,
+) -> Option<RoundUiNavigation> {
+    if keys.just_pressed(KeyCode::Tab) {
+        return Some(
+            if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+                RoundUiNavigation::Previous
+            } else {
+                RoundUiNavigation::Next
+            },
+        );
+    }
+
+    let key_directions = [
+        (KeyCode::ArrowUp, GamepadButtonType::DPadUp, Vec2::NEG_Y),
+        (KeyCode::ArrowDown, GamepadButtonType::DPadDown, Vec2::Y),
+        (KeyCode::ArrowLeft, GamepadButtonType::DPadLeft, Vec2::NEG_X),
+        (KeyCode::ArrowRight, GamepadButtonType::DPadRight, Vec2::X),
+    ];
+    key_directions
+        .into_iter()
+        .find(|(key, button_type, _)| {
+            keys.just_pressed(*key)
+                || gamepad_buttons
+                    .get_just_pressed()
+                    .any(|button| button.button_type == *button_type)
+        })
+        .map(|(_, _, direction)| RoundUiNavigation::Direction(direction))
+}
+
+/// The node that should have focus after `navigation`, given the rects of the nodes that can be focused.
+fn next_focus(
+    current: Option<Entity>,
+    nodes: &[(Entity, Rect)],
+    navigation: RoundUiNavigation,
+) -> Option<Entity> {
+    // nodes in reading order: top to bottom, then left to right
+    let mut ordered = nodes.to_vec();
+    ordered.sort_by(|(_, a), (_, b)| {
+        let (a, b) = (a.center(), b.center());
+        a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+    });
+    let current_index =
+        current.and_then(|current| ordered.iter().position(|(entity, _)| *entity == current));
+    let Some(index) = current_index else {
+        // without a focused node, start from the beginning or the end
+        let first = match navigation {
+            RoundUiNavigation::Previous => ordered.last(),
+            _ => ordered.first(),
+        };
+        return first.map(|(entity, _)| *entity);
+    };
+
+    match navigation {
+        RoundUiNavigation::Next => Some(ordered[(index + 1) % ordered.len()].0),
+        RoundUiNavigation::Previous => Some(ordered[(index + ordered.len() - 1) % ordered.len()].0),
+        RoundUiNavigation::Direction(direction) => {
+            // the closest node in the direction, preferring nodes that are in line with the focused one
+            let from = ordered[index].1.center();
+            let score = |rect: &Rect| {
+                let delta = rect.center() - from;
+                let along = delta.dot(direction);
+                let across = delta.perp_dot(direction).abs();
+                (along > 0.).then_some(along + 2. * across)
+            };
+            ordered
+                .iter()
+                .filter_map(|(entity, rect)| score(rect).map(|score| (*entity, score)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(entity, _)| entity)
+                .or(current)
+        }
+    }
+}
+
+/// Moves focus in response to keyboard and gamepad input, and drops focus from nodes that can no longer be focused.
+#[allow(clippy::type_complexity)]
+fn navigate_focus(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    mut focus: ResMut<RoundUiFocus>,
+    nodes: Query<
+        (
+            Entity,
+            &Node,
+            &GlobalTransform,
+            Option<&InheritedVisibility>,
+        ),
+        (With<RoundUiFocusable>, Without<RoundUiDisabled>),
+    >,
+) {
+    let focusable: Vec<(Entity, Rect)> = nodes
+        .iter()
+        .filter(|(_, node, _, visibility)| {
+            node.size() != Vec2::ZERO
+                && visibility
+                    .copied()
+                    .unwrap_or(InheritedVisibility::VISIBLE)
+                    .get()
+        })
+        .map(|(entity, node, transform, _)| (entity, node.logical_rect(transform)))
+        .collect();
+
+    let mut entity = focus
+        .entity
+        .filter(|entity| focusable.iter().any(|(focusable, _)| focusable == entity));
+    if let Some(navigation) = navigation_input(&keys, &gamepad_buttons) {
+        entity = next_focus(entity, &focusable, navigation);
+    }
+    if focus.entity != entity {
+        focus.entity = entity;
+    }
+}
+
+/// Presses the focused node while Enter or the gamepad South button is held.
+fn press_focused(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    focus: Res<RoundUiFocus>,
+    mut pressed: Local<Option<Entity>>,
+    mut interactions: Query<&mut Interaction>,
+) {
+    let is_press_button = |button: &GamepadButton| button.button_type == GamepadButtonType::South;
+    let held = keys.any_pressed(PRESS_KEYS) || gamepad_buttons.get_pressed().any(is_press_button);
+    let just_pressed = keys.any_just_pressed(PRESS_KEYS)
+        || gamepad_buttons.get_just_pressed().any(is_press_button);
+
+    // release the node once the button is released, or focus moves away from it
+    if let Some(entity) = *pressed {
+        if !held || focus.entity != Some(entity) {
+            if let Ok(mut interaction) = interactions.get_mut(entity) {
+                if *interaction == Interaction::Pressed {
+                    *interaction = Interaction::None;
+                }
+            }
+            *pressed = None;
+        }
+    }
+
+    if !just_pressed || pressed.is_some() {
+        return;
+    }
+    let Some(entity) = focus.entity else {
+        return;
+    };
+    if let Ok(mut interaction) = interactions.get_mut(entity) {
+        *interaction = Interaction::Pressed;
+        *pressed = Some(entity);
+    }
+}
+
+/// Gives the focused node the `RoundUiFocused` marker, and removes it from the node that lost focus.
+fn sync_focused_marker(
+    mut commands: Commands,
+    focus: Res<RoundUiFocus>,
+    focused: Query<Entity, With<RoundUiFocused>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    for entity in focused.iter() {
+        if focus.entity != Some(entity) {
+            commands.entity(entity).remove::<RoundUiFocused>();
+        }
+    }
+    if let Some(entity) = focus.entity {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(RoundUiFocused);
+        }
+    }
+}
+
+/// Makes the focus ring follow the corner radii of the focused node, if it has the material `M`.
+fn focus_ring_radius<M: RoundUiFocusRingMaterial>(
+    focus: Res<RoundUiFocus>,
+    nodes: Query<&Handle<M>>,
+    materials: Res<Assets<M>>,
+    mut state: ResMut<RoundUiFocusRingState>,
+) {
+    let Some(material) = focus
+        .entity
+        .and_then(|entity| nodes.get(entity).ok())
+        .and_then(|handle| materials.get(handle))
+    else {
+        return;
+    };
+    state.target_radius = material.focus_ring_radius();
+}
+
+/// Moves the focus ring towards the focused node, fading it in and out as focus is gained and lost.
+#[allow(clippy::too_many_arguments)]
+fn animate_focus_ring(
+    mut commands: Commands,
+    time: Res<Time>,
+    focus: Res<RoundUiFocus>,
+    settings: Res<RoundUiFocusRing>,
+    nodes: Query<(&Node, &GlobalTransform)>,
+    mut rings: Query<(&mut Style, &mut Visibility)>,
+    mut materials: ResMut<Assets<RoundRectUiMaterial>>,
+    mut state: ResMut<RoundUiFocusRingState>,
+) {
+    let Some((ring, handle)) = state.ring.clone() else {
+        // The ring is a root node drawn over everything else. It passes interactions through to the nodes below.
+        let handle = materials.add(RoundRectUiMaterial {
+            background_color: LinearRgba::NONE,
+            ..default()
+        });
+        let ring = commands
+            .spawn((
+                MaterialNodeBundle {
+                    material: handle.clone(),
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    z_index: ZIndex::Global(i32::MAX),
+                    ..default()
+                },
+                Name::new("Focus Ring"),
+            ))
+            .id();
+        state.ring = Some((ring, handle));
+        return;
+    };
+
+    let target = focus
+        .entity
+        .and_then(|entity| nodes.get(entity).ok())
+        .map(|(node, transform)| node.logical_rect(transform));
+    // the radius is found again each frame, so nodes without a supported material get square corners
+    let target_radius = std::mem::take(&mut state.target_radius);
+    if target.is_none() && state.alpha == 0. {
+        return;
+    }
+
+    let t = 1. - (-settings.speed * time.delta_seconds()).exp();
+    if let Some(target) = target {
+        if state.alpha == 0. {
+            // appear at the focused node, rather than moving from where the ring last disappeared
+            state.rect = target;
+            state.radius = target_radius;
+        } else {
+            state.rect = Rect::from_corners(
+                state.rect.min.lerp(target.min, t),
+                state.rect.max.lerp(target.max, t),
+            );
+            state.radius = state.radius.lerp(target_radius, t);
+        }
+        state.alpha = if state.alpha > 0.99 {
+            1.
+        } else {
+            state.alpha.lerp(1., t).max(0.01)
+        };
+    } else {
+        state.alpha = if state.alpha < 0.01 {
+            0.
+        } else {
+            state.alpha.lerp(0., t)
+        };
+    }
+
+    if let Ok((mut style, mut visibility)) = rings.get_mut(ring) {
+        let size = state.rect.size();
+        let (left, top) = (Val::Px(state.rect.min.x), Val::Px(state.rect.min.y));
+        let (width, height) = (Val::Px(size.x), Val::Px(size.y));
+        if style.left != left || style.top != top || style.width != width || style.height != height
+        {
+            style.left = left;
+            style.top = top;
+            style.width = width;
+            style.height = height;
+        }
+        let shown = if state.alpha > 0. {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+
+    let outline = RoundUiOutline {
+        color: settings
+            .color
+            .with_alpha(settings.color.alpha * state.alpha),
+        width: settings.width,
+        offset: settings.offset,
+    };
+    let changed = materials.get(&handle).is_some_and(|material| {
+        material.outline != outline || material.border_radius != state.radius
+    });
+    if changed {
+        if let Some(material) = materials.get_mut(&handle) {
+            material.outline = outline;
+            material.border_radius = state.radius;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn nodes(world: &mut World) -> Vec<(Entity, Rect)> {
+        // a row of two nodes above a wide node
+        [
+            Rect::new(0., 0., 100., 50.),
+            Rect::new(120., 0., 220., 50.),
+            Rect::new(0., 80., 220., 130.),
+        ]
+        .into_iter()
+        .map(|rect| (world.spawn_empty().id(), rect))
+        .collect()
+    }
+
+    #[test]
+    fn tab_order() {
+        let mut world = World::new();
+        let nodes = nodes(&mut world);
+        let [a, b, c] = [nodes[0].0, nodes[1].0, nodes[2].0];
+
+        assert_eq!(next_focus(None, &nodes, RoundUiNavigation::Next), Some(a));
+        assert_eq!(
+            next_focus(None, &nodes, RoundUiNavigation::Previous),
+            Some(c)
+        );
+        assert_eq!(
+            next_focus(Some(a), &nodes, RoundUiNavigation::Next),
+            Some(b)
+        );
+        assert_eq!(
+            next_focus(Some(c), &nodes, RoundUiNavigation::Next),
+            Some(a)
+        );
+        assert_eq!(
+            next_focus(Some(a), &nodes, RoundUiNavigation::Previous),
+            Some(c)
+        );
+    }
+
+    #[test]
+    fn directional_navigation() {
+        let mut world = World::new();
+        let nodes = nodes(&mut world);
+        let [a, b, c] = [nodes[0].0, nodes[1].0, nodes[2].0];
+        let direction = |current, direction| {
+            next_focus(
+                Some(current),
+                &nodes,
+                RoundUiNavigation::Direction(direction),
+            )
+        };
+
+        assert_eq!(direction(a, Vec2::X), Some(b));
+        assert_eq!(direction(b, Vec2::NEG_X), Some(a));
+        assert_eq!(direction(a, Vec2::Y), Some(c));
+        assert_eq!(direction(c, Vec2::NEG_Y), Some(a));
+        // there is nothing to the left of the first node, so focus stays
+        assert_eq!(direction(a, Vec2::NEG_X), Some(a));
+    }
+
+    #[test]
+    fn enter_presses_focused_node() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<GamepadButton>>()
+            .init_resource::<RoundUiFocus>()
+            .add_systems(Update, (press_focused, sync_focused_marker).chain());
+
+        let entity = app
+            .world_mut()
+            .spawn((Interaction::default(), RoundUiFocusable))
+            .id();
+        app.world_mut().resource_mut::<RoundUiFocus>().entity = Some(entity);
+        app.update();
+        assert!(app.world().get::<RoundUiFocused>(entity).is_some());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        app.update();
+        assert_eq!(
+            app.world().get::<Interaction>(entity),
+            Some(&Interaction::Pressed)
+        );
+
+        // Inputs are usually cleared each frame by the `InputPlugin`
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.clear();
+        keys.release(KeyCode::Enter);
+        app.update();
+        assert_eq!(
+            app.world().get::<Interaction>(entity),
+            Some(&Interaction::None)
+        );
+
+        // Losing focus removes the marker
+        app.world_mut().resource_mut::<RoundUiFocus>().entity = None;
+        app.update();
+        assert!(app.world().get::<RoundUiFocused>(entity).is_none());
+    }
+}
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+
+use crate::{transition::RoundUiTransitionState, types::RoundUiSystem};
+
+/// Marker component for nodes that are disabled, shown with `RoundUiInteractionStyle::disabled`.
+///
+/// Disabled nodes still receive `Interaction` changes, so click handlers should skip them.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiDisabled;
+
+/// Marker component for nodes that have focus, shown with `RoundUiInteractionStyle::focused`.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiFocused;
+
+/// Marker component for nodes that are selected, shown with `RoundUiInteractionStyle::selected`.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiSelected;
+
+/// The visual state of an interactive node.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiInteractionState {
+    #[default]
+    Default,
+    Hovered,
+    Pressed,
+    Disabled,
+    Focused,
+    Selected,
+}
+
+impl RoundUiInteractionState {
+    /// The state of a node, in order of priority: disabled, pressed, hovered, focused, selected, and finally default.
+    pub fn new(interaction: Interaction, disabled: bool, focused: bool, selected: bool) -> Self {
+        if disabled {
+            return Self::Disabled;
+        }
+        match interaction {
+            Interaction::Pressed => Self::Pressed,
+            Interaction::Hovered => Self::Hovered,
+            Interaction::None if focused => Self::Focused,
+            Interaction::None if selected => Self::Selected,
+            Interaction::None => Self::Default,
+        }
+    }
+}
+
+/// Maps the interaction states of a node to materials, which are swapped automatically as the state changes.
+///
+/// The state is taken from the `Interaction` of the node, and the `RoundUiDisabled`, `RoundUiFocused` and
+/// `RoundUiSelected` marker components. States without a material use the `default` material.
+///
+/// Add a `RoundUiAutoPadding` component as well to shift the content of the node along with the offset of each
+/// material, so a pressed 3D button looks pushed in.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn spawn_button(mut commands: Commands, mut materials: ResMut<Assets<RoundRectUiMaterial>>) {
+///     let style = RoundUiInteractionStyle::new(materials.add(RoundRectUiMaterial {
+///         offset: RoundUiOffset::bottom(5.).into(),
+///         ..default()
+///     }))
+///     .with_pressed(materials.add(RoundRectUiMaterial {
+///         offset: RoundUiOffset::top(5.).into(),
+///         ..default()
+///     }));
+///
+///     commands.spawn((
+///         MaterialNodeBundle {
+///             material: style.default.clone(),
+///             ..default()
+///         },
+///         Interaction::default(),
+///         style,
+///         RoundUiAutoPadding::default(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct RoundUiInteractionStyle<M: UiMaterial> {
+    pub default: Handle<M>,
+    pub hovered: Option<Handle<M>>,
+    pub pressed: Option<Handle<M>>,
+    pub disabled: Option<Handle<M>>,
+    pub focused: Option<Handle<M>>,
+    pub selected: Option<Handle<M>>,
+}
+
+impl<M: UiMaterial> RoundUiInteractionStyle<M> {
+    pub fn new(default: Handle<M>) -> Self {
+        Self {
+            default,
+            hovered: None,
+            pressed: None,
+            disabled: None,
+            focused: None,
+            selected: None,
+        }
+    }
+
+    pub fn with_hovered(mut self, hovered: Handle<M>) -> Self {
+        self.hovered = Some(hovered);
+        self
+    }
+
+    pub fn with_pressed(mut self, pressed: Handle<M>) -> Self {
+        self.pressed = Some(pressed);
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: Handle<M>) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn with_focused(mut self, focused: Handle<M>) -> Self {
+        self.focused = Some(focused);
+        self
+    }
+
+    pub fn with_selected(mut self, selected: Handle<M>) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// The material for the given state, falling back to the `default` material.
+    pub fn material(&self, state: RoundUiInteractionState) -> &Handle<M> {
+        let material = match state {
+            RoundUiInteractionState::Default => None,
+            RoundUiInteractionState::Hovered => self.hovered.as_ref(),
+            RoundUiInteractionState::Pressed => self.pressed.as_ref(),
+            RoundUiInteractionState::Disabled => self.disabled.as_ref(),
+            RoundUiInteractionState::Focused => self.focused.as_ref(),
+            RoundUiInteractionState::Selected => self.selected.as_ref(),
+        };
+        material.unwrap_or(&self.default)
+    }
+}
+
+/// Adds the system applying `RoundUiInteractionStyle<M>` components.
+pub(crate) fn add_interaction_systems<M: UiMaterial>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        apply_interaction_styles::<M>.in_set(RoundUiSystem::Interaction),
+    );
+}
+
+/// Swaps the material of each node with a `RoundUiInteractionStyle` to match its state.
+///
+/// Handles are only replaced when they differ, so the material handle is only marked as changed when the state does.
+/// Nodes with a `RoundUiTransition` are compared by the target of their transition, since they display a material of
+/// their own.
+#[allow(clippy::type_complexity)]
+fn apply_interaction_styles<M: UiMaterial>(
+    mut query: Query<(
+        &RoundUiInteractionStyle<M>,
+        &mut Handle<M>,
+        Option<&Interaction>,
+        Has<RoundUiDisabled>,
+        Has<RoundUiFocused>,
+        Has<RoundUiSelected>,
+        Option<&RoundUiTransitionState<M>>,
+    )>,
+) {
+    for (style, mut handle, interaction, disabled, focused, selected, transition) in
+        query.iter_mut()
+    {
+        let state = RoundUiInteractionState::new(
+            interaction.copied().unwrap_or_default(),
+            disabled,
+            focused,
+            selected,
+        );
+        let material = style.material(state);
+        let current = transition.map_or(&*handle, |transition| transition.target());
+        if *current != *material {
+            *handle = material.clone();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "round_rect"))]
+mod test {
+    use super::*;
+    use crate::{round_rect::RoundRectUiMaterial, test_util::*};
+
+    #[test]
+    fn state_priority() {
+        use RoundUiInteractionState as State;
+        assert_eq!(
+            State::new(Interaction::Pressed, true, true, true),
+            State::Disabled
+        );
+        assert_eq!(
+            State::new(Interaction::Pressed, false, true, true),
+            State::Pressed
+        );
+        assert_eq!(
+            State::new(Interaction::Hovered, false, true, true),
+            State::Hovered
+        );
+        assert_eq!(
+            State::new(Interaction::None, false, true, true),
+            State::Focused
+        );
+        assert_eq!(
+            State::new(Interaction::None, false, false, true),
+            State::Selected
+        );
+        assert_eq!(
+            State::new(Interaction::None, false, false, false),
+            State::Default
+        );
+    }
+
+    #[test]
+    fn materials_follow_interaction() {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.add_systems(Update, apply_interaction_styles::<RoundRectUiMaterial>);
+
+        let default = add_material(&mut app, RoundRectUiMaterial::default());
+        let pressed = add_material(&mut app, RoundRectUiMaterial::default());
+        let disabled = add_material(&mut app, RoundRectUiMaterial::default());
+        let style = RoundUiInteractionStyle::new(default.clone())
+            .with_pressed(pressed.clone())
+            .with_disabled(disabled.clone());
+        let entity = app
+            .world_mut()
+            .spawn((default.clone(), Interaction::Pressed, style))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Handle<RoundRectUiMaterial>>(entity),
+            Some(&pressed)
+        );
+
+        // Hovered has no material of its own, so it uses the default one
+        *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(
+            app.world().get::<Handle<RoundRectUiMaterial>>(entity),
+            Some(&default)
+        );
+
+        app.world_mut().entity_mut(entity).insert(RoundUiDisabled);
+        app.update();
+        assert_eq!(
+            app.world().get::<Handle<RoundRectUiMaterial>>(entity),
+            Some(&disabled)
+        );
+    }
+}
@@ -1,18 +1,99 @@
+//! UI materials for Bevy that draw rounded rects, superellipses, arcs and polygons, with borders, gradients, images,
+//! shadows and outlines.
+//!
+//! # Edge softness
+//!
+//! Every material, and `RoundRectUiInstance`, anti-aliases its edges over a transition about one pixel wide, so they
+//! stay smooth at any scale. Its `edge_softness` property widens the transition to that many pixels, for deliberately
+//! soft edges. Values up to the width of a pixel leave the edges as they are.
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+mod cooldown;
+#[cfg(any(
+    feature = "round_rect",
+    feature = "superellipse",
+    feature = "arc",
+    feature = "polygon"
+))]
+mod interaction;
+#[cfg(any(feature = "round_rect", feature = "superellipse"))]
+mod managed;
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+mod padding;
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+mod responsive;
+#[cfg(any(
+    feature = "round_rect",
+    feature = "superellipse",
+    feature = "arc",
+    feature = "polygon"
+))]
+mod shared;
+#[cfg(any(
+    feature = "round_rect",
+    feature = "superellipse",
+    feature = "arc",
+    feature = "polygon"
+))]
+mod transition;
 mod types;
 
+#[cfg(feature = "arc")]
+mod arc;
+#[cfg(feature = "round_rect")]
+mod focus;
+#[cfg(feature = "polygon")]
+mod polygon;
 #[cfg(feature = "round_rect")]
 mod round_rect;
+#[cfg(feature = "round_rect")]
+mod round_rect_instanced;
 
 #[cfg(feature = "superellipse")]
 mod superellipse;
 
+#[cfg(all(
+    test,
+    any(feature = "round_rect", feature = "superellipse", feature = "polygon")
+))]
+mod test_util;
+
 pub mod prelude {
     use bevy::prelude::*;
 
     pub use crate::types::*;
 
+    #[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+    pub use crate::cooldown::*;
+    #[cfg(any(
+        feature = "round_rect",
+        feature = "superellipse",
+        feature = "arc",
+        feature = "polygon"
+    ))]
+    pub use crate::interaction::*;
+    #[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+    pub use crate::padding::*;
+    #[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+    pub use crate::responsive::*;
+    #[cfg(any(
+        feature = "round_rect",
+        feature = "superellipse",
+        feature = "arc",
+        feature = "polygon"
+    ))]
+    pub use crate::transition::*;
+
+    #[cfg(feature = "arc")]
+    pub use crate::arc::*;
+    #[cfg(feature = "round_rect")]
+    pub use crate::focus::*;
+    #[cfg(feature = "polygon")]
+    pub use crate::polygon::*;
     #[cfg(feature = "round_rect")]
     pub use crate::round_rect::*;
+    #[cfg(feature = "round_rect")]
+    pub use crate::round_rect_instanced::*;
     #[cfg(feature = "superellipse")]
     pub use crate::superellipse::*;
 
@@ -25,6 +106,10 @@ pub mod prelude {
             app.add_plugins(RoundRectMaterialPlugin);
             #[cfg(feature = "superellipse")]
             app.add_plugins(SuperellipseMaterialPlugin);
+            #[cfg(feature = "arc")]
+            app.add_plugins(ArcMaterialPlugin);
+            #[cfg(feature = "polygon")]
+            app.add_plugins(PolygonMaterialPlugin);
         }
     }
 }
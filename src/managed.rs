@@ -0,0 +1,145 @@
+use std::{hash::Hash, sync::Arc};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::types::RoundUiSystem;
+
+/// A component describing a material, which the plugin turns into a shared material asset.
+pub(crate) trait RoundUiManagedStyle: Component {
+    type Material: UiMaterial;
+
+    /// Identical keys share the same material.
+    type Key: Hash + Eq + Send + Sync + 'static;
+
+    fn key(&self) -> Self::Key;
+
+    fn material(&self) -> Self::Material;
+}
+
+/// The materials created for the `RoundUiManagedStyle` components `S`, shared by every node with an identical style.
+#[derive(Resource)]
+pub(crate) struct RoundUiManagedMaterials<S: RoundUiManagedStyle> {
+    handles: HashMap<S::Key, Handle<S::Material>>,
+}
+
+impl<S: RoundUiManagedStyle> Default for RoundUiManagedMaterials<S> {
+    fn default() -> Self {
+        Self {
+            handles: HashMap::default(),
+        }
+    }
+}
+
+/// Adds the systems turning `S` components into materials.
+pub(crate) fn add_managed_style_systems<S: RoundUiManagedStyle>(app: &mut App) {
+    app.init_resource::<RoundUiManagedMaterials<S>>()
+        .add_systems(
+            PostUpdate,
+            (apply_managed_styles::<S>, free_unused_materials::<S>)
+                .chain()
+                .in_set(RoundUiSystem::Style),
+        );
+}
+
+/// Gives each node with a changed style the material for that style, creating it if no other node uses it.
+#[allow(clippy::type_complexity)]
+fn apply_managed_styles<S: RoundUiManagedStyle>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &S, Option<&mut Handle<S::Material>>), Changed<S>>,
+    mut managed: ResMut<RoundUiManagedMaterials<S>>,
+    mut materials: ResMut<Assets<S::Material>>,
+) {
+    for (entity, style, handle) in query.iter_mut() {
+        let material = managed
+            .handles
+            .entry(style.key())
+            .or_insert_with(|| materials.add(style.material()))
+            .clone();
+        match handle {
+            Some(mut handle) => {
+                if *handle != material {
+                    *handle = material;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(material);
+            }
+        }
+    }
+}
+
+/// Drops the materials that are no longer used by any node, so their assets are freed.
+fn free_unused_materials<S: RoundUiManagedStyle>(mut managed: ResMut<RoundUiManagedMaterials<S>>) {
+    managed.handles.retain(|_, handle| match handle {
+        Handle::Strong(handle) => Arc::strong_count(handle) > 1,
+        Handle::Weak(_) => false,
+    });
+}
+
+/// The components of a color, for building style keys.
+pub(crate) fn color_components(color: LinearRgba) -> [f32; 4] {
+    [color.red, color.green, color.blue, color.alpha]
+}
+
+/// The bits of a list of floats, for building style keys.
+pub(crate) fn float_bits<const N: usize>(values: [f32; N]) -> [u32; N] {
+    values.map(f32::to_bits)
+}
+
+#[cfg(all(test, feature = "round_rect"))]
+mod test {
+    use super::*;
+    use crate::{round_rect::*, test_util::*, types::*};
+
+    #[test]
+    fn identical_styles_share_materials() {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.init_resource::<RoundUiManagedMaterials<RoundRectUiStyle>>()
+            .add_systems(
+                Update,
+                (
+                    apply_managed_styles::<RoundRectUiStyle>,
+                    free_unused_materials::<RoundRectUiStyle>,
+                )
+                    .chain(),
+            );
+
+        let style = RoundRectUiStyle {
+            border_radius: RoundUiBorder::all(10.),
+            ..default()
+        };
+        let a = app.world_mut().spawn(style).id();
+        let b = app.world_mut().spawn(style).id();
+        app.update();
+
+        let handle = material_handle::<RoundRectUiMaterial>;
+        let shared = handle(&app, a);
+        assert_eq!(shared, handle(&app, b));
+        assert_eq!(material(&app, &shared).border_radius, Vec4::splat(10.));
+
+        // Changing one node gives it a new material, and leaves the other one alone
+        app.world_mut()
+            .get_mut::<RoundRectUiStyle>(b)
+            .unwrap()
+            .border_radius = RoundUiBorder::all(20.);
+        app.update();
+        let changed = handle(&app, b);
+        assert_ne!(changed, shared);
+        assert_eq!(handle(&app, a), shared);
+
+        // Materials are freed once no node uses them
+        let changed_id = changed.id();
+        drop(changed);
+        app.world_mut().despawn(b);
+        app.update();
+        app.update();
+        let managed = app
+            .world()
+            .resource::<RoundUiManagedMaterials<RoundRectUiStyle>>();
+        assert_eq!(managed.handles.len(), 1);
+        assert!(!app
+            .world()
+            .resource::<Assets<RoundRectUiMaterial>>()
+            .contains(changed_id));
+    }
+}
@@ -0,0 +1,250 @@
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::types::*;
+
+/// How `RoundUiAutoPadding` insets the content of a node.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiPaddingMode {
+    /// Pad each side by its offset, so the content is centered on the inner area of the material. The border thickness
+    /// of `SuperellipseUiMaterial` and `PolygonUiMaterial` is included, as the inner area is inside its border.
+    #[default]
+    Offset,
+    /// Pad each side by its offset and the largest radius of its corners, as `RoundRectUiMaterial::get_padding` does,
+    /// so the content stays clear of the corners.
+    Corners,
+}
+
+/// Keeps the `Style::padding` of a material node in sync with its material.
+///
+/// The padding is updated when the node's material handle changes, when the material itself is modified and when this
+/// component changes, so content stays inside the material as it switches between states.
+///
+/// # Example:
+///
+/// ```
+/// use bevy_round_ui::prelude::*;
+///
+/// // Pad by the offset of the material, plus 8 pixels on the left and right
+/// let auto_padding = RoundUiAutoPadding {
+///     extra: RoundUiOffset {
+///         left: 8.,
+///         right: 8.,
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// assert_eq!(auto_padding.mode, RoundUiPaddingMode::Offset);
+/// ```
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiAutoPadding {
+    /// Which parts of the material the padding is computed from
+    pub mode: RoundUiPaddingMode,
+    /// Extra padding in pixels, added to the padding of each side
+    pub extra: RoundUiOffset,
+}
+
+impl RoundUiAutoPadding {
+    pub fn new(mode: RoundUiPaddingMode) -> Self {
+        Self {
+            mode,
+            extra: RoundUiOffset::default(),
+        }
+    }
+
+    pub fn with_extra(mut self, extra: RoundUiOffset) -> Self {
+        self.extra = extra;
+        self
+    }
+}
+
+/// A material that can compute the padding needed to keep content inside its shape.
+pub(crate) trait RoundUiPaddedMaterial: UiMaterial {
+    fn padding(&self, mode: RoundUiPaddingMode) -> RoundUiOffset;
+}
+
+/// Adds the system keeping the padding of `RoundUiAutoPadding` nodes in sync with the material `M`.
+pub(crate) fn add_padding_systems<M: RoundUiPaddedMaterial>(app: &mut App) {
+    app.add_systems(PostUpdate, sync_padding::<M>.in_set(RoundUiSystem::Padding));
+}
+
+/// Updates the padding of nodes whose material handle, material or `RoundUiAutoPadding` changed.
+#[allow(clippy::type_complexity)]
+fn sync_padding<M: RoundUiPaddedMaterial>(
+    mut events: EventReader<AssetEvent<M>>,
+    materials: Res<Assets<M>>,
+    mut query: Query<(Ref<Handle<M>>, Ref<RoundUiAutoPadding>, &mut Style)>,
+) {
+    let modified: HashSet<AssetId<M>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (handle, auto_padding, mut style) in query.iter_mut() {
+        if !handle.is_changed() && !auto_padding.is_changed() && !modified.contains(&handle.id()) {
+            continue;
+        }
+        let Some(material) = materials.get(&*handle) else {
+            continue;
+        };
+        let padding: UiRect = (material.padding(auto_padding.mode) + auto_padding.extra).into();
+        if style.padding != padding {
+            style.padding = padding;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "round_rect"))]
+mod test {
+    use super::*;
+    use crate::{round_rect::RoundRectUiMaterial, test_util::*};
+
+    fn setup_app() -> App {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.add_systems(Update, sync_padding::<RoundRectUiMaterial>);
+        app
+    }
+
+    #[test]
+    fn padding_follows_material_changes() {
+        let mut app = setup_app();
+        let handle = add_material(
+            &mut app,
+            RoundRectUiMaterial {
+                offset: RoundUiOffset::bottom(5.).into(),
+                ..default()
+            },
+        );
+        let entity = app
+            .world_mut()
+            .spawn((
+                handle.clone(),
+                Style::default(),
+                RoundUiAutoPadding::default().with_extra(RoundUiOffset::all(2.)),
+            ))
+            .id();
+
+        app.update();
+        let style = app.world().get::<Style>(entity).unwrap();
+        assert_eq!(style.padding.top, Val::Px(2.));
+        assert_eq!(style.padding.bottom, Val::Px(7.));
+
+        // Modifying the material updates the padding once its asset event has been sent
+        app.world_mut()
+            .resource_mut::<Assets<RoundRectUiMaterial>>()
+            .get_mut(&handle)
+            .unwrap()
+            .offset = RoundUiOffset::top(5.).into();
+        app.update();
+        app.update();
+        let style = app.world().get::<Style>(entity).unwrap();
+        assert_eq!(style.padding.top, Val::Px(7.));
+        assert_eq!(style.padding.bottom, Val::Px(2.));
+    }
+
+    #[test]
+    fn corner_mode_includes_radius() {
+        let mut app = setup_app();
+        let handle = add_material(
+            &mut app,
+            RoundRectUiMaterial {
+                border_radius: RoundUiBorder::top(10.).into(),
+                offset: RoundUiOffset::bottom(5.).into(),
+                ..default()
+            },
+        );
+        let entity = app
+            .world_mut()
+            .spawn((
+                handle,
+                Style::default(),
+                RoundUiAutoPadding::new(RoundUiPaddingMode::Corners),
+            ))
+            .id();
+
+        app.update();
+        let style = app.world().get::<Style>(entity).unwrap();
+        assert_eq!(style.padding.top, Val::Px(10.));
+        assert_eq!(style.padding.left, Val::Px(10.));
+        assert_eq!(style.padding.bottom, Val::Px(5.));
+    }
+
+    #[test]
+    fn corner_mode_includes_smoothing() {
+        let material = RoundRectUiMaterial {
+            border_radius: RoundUiBorder::all(10.).into(),
+            corner_smoothing: 0.5,
+            ..default()
+        };
+        assert_eq!(
+            material.padding(RoundUiPaddingMode::Corners),
+            RoundUiOffset::all(15.)
+        );
+    }
+
+    #[test]
+    fn stroke_wider_than_offset_pads_content() {
+        let material = RoundRectUiMaterial {
+            offset: RoundUiOffset::bottom(5.).into(),
+            stroke_width: 2.,
+            ..default()
+        };
+        assert_eq!(
+            material.padding(RoundUiPaddingMode::Offset),
+            RoundUiOffset {
+                top: 2.,
+                left: 2.,
+                bottom: 5.,
+                right: 2.,
+            }
+        );
+    }
+
+    #[cfg(feature = "superellipse")]
+    #[test]
+    fn superellipse_padding_includes_border() {
+        use crate::superellipse::SuperellipseUiMaterial;
+
+        let material = SuperellipseUiMaterial {
+            border_radius: RoundUiBorder::all(10.).into(),
+            border_thickness: RoundUiOffset::all(2.).into(),
+            offset: RoundUiOffset::bottom(5.).into(),
+            ..default()
+        };
+        assert_eq!(
+            material.padding(RoundUiPaddingMode::Offset),
+            RoundUiOffset {
+                top: 2.,
+                left: 2.,
+                bottom: 7.,
+                right: 2.,
+            }
+        );
+        assert_eq!(material.get_padding().bottom, Val::Px(17.));
+    }
+
+    #[cfg(feature = "polygon")]
+    #[test]
+    fn polygon_padding_includes_border() {
+        use crate::polygon::PolygonUiMaterial;
+
+        let material = PolygonUiMaterial {
+            border_thickness: 2.,
+            corner_radius: 4.,
+            offset: RoundUiOffset::bottom(5.).into(),
+            ..default()
+        };
+        assert_eq!(
+            material.padding(RoundUiPaddingMode::Offset),
+            RoundUiOffset {
+                top: 2.,
+                left: 2.,
+                bottom: 7.,
+                right: 2.,
+            }
+        );
+        assert_eq!(material.get_padding().bottom, Val::Px(11.));
+    }
+}
@@ -0,0 +1,363 @@
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    render::{render_asset::RenderAssets, render_resource::*, texture::GpuImage},
+};
+
+use crate::{
+    cooldown::{add_cooldown_systems, RoundUiSweepMaskMaterial},
+    interaction::add_interaction_systems,
+    padding::{add_padding_systems, RoundUiPaddedMaterial, RoundUiPaddingMode},
+    responsive::{add_responsive_systems, RoundUiResponsiveMaterial},
+    shared::{
+        configure_system_sets, load_shared_shaders, RoundUiBorderStyleUniform,
+        RoundUiGradientUniform, RoundUiImageUniform, RoundUiSweepMaskUniform,
+    },
+    transition::{
+        add_transition_systems, mix_color, mix_f32, mix_outline, mix_shadow,
+        RoundUiTransitionMaterial,
+    },
+    types::*,
+};
+
+pub const POLYGON_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(27184590316642817093);
+
+/// Plugin which adds a `PolygonUiMaterial` to the app.
+pub struct PolygonMaterialPlugin;
+
+impl Plugin for PolygonMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        load_shared_shaders(app);
+        configure_system_sets(app);
+        load_internal_asset!(
+            app,
+            POLYGON_SHADER_HANDLE,
+            "polygon.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(UiMaterialPlugin::<PolygonUiMaterial>::default());
+        add_responsive_systems::<PolygonUiMaterial>(app);
+        add_interaction_systems::<PolygonUiMaterial>(app);
+        add_transition_systems::<PolygonUiMaterial>(app);
+        add_cooldown_systems::<PolygonUiMaterial>(app);
+        add_padding_systems::<PolygonUiMaterial>(app);
+    }
+}
+
+/// UI Material that renders a regular polygon or a star, with rounded points and an optional border, e.g. for
+/// hexagonal skill trees and star ratings.
+///
+/// The shape fills the largest circle that fits in the node, with its first point straight up. A star has `points`
+/// outer vertices, with inner vertices between them pulled towards the center by `inner_radius`.
+///
+/// It has the fills, border styles, stroke, offset, progress fill and sweep mask of `RoundRectUiMaterial`, and works
+/// with `RoundUiCooldown`, `RoundUiResponsive` and `RoundUiAutoPadding`. It has no per-corner `border_radius`, so the
+/// border has the same thickness all the way around, and it isn't supported by the corner radii of the focus ring.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// // A hexagon with a flat top and slightly rounded corners
+/// let hexagon = PolygonUiMaterial {
+///     points: 6,
+///     rotation: std::f32::consts::PI / 6.,
+///     corner_radius: 4.,
+///     ..default()
+/// };
+///
+/// // A five-pointed star
+/// let star = PolygonUiMaterial {
+///     points: 5,
+///     inner_radius: 0.4,
+///     ..default()
+/// };
+/// assert_eq!(star.rotation, 0.);
+/// ```
+#[derive(AsBindGroup, Asset, Debug, Clone, Reflect)]
+#[uniform(0, PolygonUiMaterialUniform)]
+#[reflect(Default, Debug)]
+pub struct PolygonUiMaterial {
+    /// The background color of the material
+    pub background_color: LinearRgba,
+
+    /// Gradient used for the background instead of `background_color`
+    pub background_gradient: Option<RoundUiGradient>,
+
+    /// Image drawn on top of the background, clipped to the shape. Render target images can be used to show the
+    /// output of a camera.
+    #[texture(1)]
+    #[sampler(2)]
+    pub image: Option<Handle<Image>>,
+
+    /// How the image is fitted into the node, inside the border
+    pub image_fit: RoundUiImageFit,
+
+    /// Color the image is multiplied by
+    pub image_tint: LinearRgba,
+
+    /// The border color of the material
+    pub border_color: LinearRgba,
+
+    /// Gradient used for the border instead of `border_color`
+    pub border_gradient: Option<RoundUiGradient>,
+
+    /// Whether the border is solid, dashed or dotted. Dots are as wide as the border.
+    pub border_style: RoundUiBorderStyle,
+
+    /// The thickness of the border in pixels, drawn inside the edge of the shape
+    pub border_thickness: f32,
+
+    /// The offset along each side of the shape, like `RoundRectUiMaterial::offset`
+    /// E.g. Vec4::new(top, left, bottom, right)
+    ///
+    /// The face of the shape fills the node shrunk away from each side by its offset, and is drawn over a base of the
+    /// border color filling the whole node. E.g. `RoundUiOffset::bottom(10.0).into()` gives a raised 3D look. The
+    /// border, image, progress fill and inset shadow are drawn inside the face.
+    pub offset: Vec4,
+
+    /// The number of points of a star, or sides of a regular polygon. Values below 3 are treated as 3.
+    pub points: u32,
+
+    /// The distance of the inner vertices of a star from the center, relative to the distance of its points.
+    ///
+    /// Values from `cos(PI / points)` up to `1.0` put the inner vertices on the sides of a regular polygon, so the
+    /// default of `1.0` gives a regular polygon. Smaller values give a star, e.g. `0.4` for a classic five-pointed star.
+    pub inner_radius: f32,
+
+    /// The rotation of the shape in radians. As in CSS, positive angles rotate clockwise.
+    pub rotation: f32,
+
+    /// The radius of the rounded points, in pixels. The sides of the shape are not moved, so rounding doesn't change
+    /// its size along the sides.
+    pub corner_radius: f32,
+
+    /// Color of the stroke drawn along the outer edge of the shape
+    pub stroke_color: LinearRgba,
+
+    /// Width of the stroke drawn along the outer edge of the shape, in pixels. It is drawn on top of the border.
+    pub stroke_width: f32,
+
+    /// Width of the anti-aliased edges of the shape, in pixels. See [edge softness](crate#edge-softness).
+    pub edge_softness: f32,
+
+    /// Drop shadow drawn behind the material. It is rendered outside the bounds of the node if needed.
+    pub shadow: RoundUiShadow,
+
+    /// Inner shadow drawn inside the border, on top of the background.
+    pub inset_shadow: RoundUiShadow,
+
+    /// Outline drawn around the outside of the material, following its shape. It is rendered outside the bounds of the
+    /// node, so it doesn't affect the layout.
+    pub outline: RoundUiOutline,
+
+    /// Color of the progress fill, drawn over the background
+    pub fill_color: LinearRgba,
+
+    /// How much of the shape the progress fill covers, from `0.0` (empty) to `1.0` (full), e.g. for partially filled
+    /// rating stars.
+    ///
+    /// The fraction is measured across the circle the shape fills, and the fill is clipped to the area inside the
+    /// border, with a straight leading edge.
+    pub fill_fraction: f32,
+
+    /// The side the progress fill starts from
+    pub fill_direction: RoundUiFillDirection,
+
+    /// "Clock wipe" overlay drawn over the material and clipped to its shape, e.g. for the cooldown of an ability
+    /// button. It sweeps around the center of the shape.
+    pub sweep_mask: RoundUiSweepMask,
+}
+
+impl Default for PolygonUiMaterial {
+    fn default() -> Self {
+        Self {
+            background_color: LinearRgba::WHITE,
+            background_gradient: None,
+            image: None,
+            image_fit: RoundUiImageFit::default(),
+            image_tint: LinearRgba::WHITE,
+            border_color: LinearRgba::NONE,
+            border_gradient: None,
+            border_style: RoundUiBorderStyle::default(),
+            border_thickness: 0.,
+            offset: Vec4::ZERO,
+            points: 6,
+            inner_radius: 1.,
+            rotation: 0.,
+            corner_radius: 0.,
+            stroke_color: LinearRgba::NONE,
+            stroke_width: 0.,
+            edge_softness: 0.,
+            shadow: RoundUiShadow::default(),
+            inset_shadow: RoundUiShadow::default(),
+            outline: RoundUiOutline::default(),
+            fill_color: LinearRgba::NONE,
+            fill_fraction: 0.,
+            fill_direction: RoundUiFillDirection::default(),
+            sweep_mask: RoundUiSweepMask::default(),
+        }
+    }
+}
+
+pub use shader_types::PolygonUiMaterialUniform;
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// The GPU representation of a `PolygonUiMaterial`.
+    #[derive(Clone, Default, ShaderType)]
+    pub struct PolygonUiMaterialUniform {
+        pub(super) background_color: LinearRgba,
+        pub(super) border_color: LinearRgba,
+        pub(super) border_thickness: f32,
+        pub(super) points: u32,
+        pub(super) inner_radius: f32,
+        pub(super) rotation: f32,
+        pub(super) corner_radius: f32,
+        pub(super) edge_softness: f32,
+        pub(super) shadow: RoundUiShadow,
+        pub(super) inset_shadow: RoundUiShadow,
+        pub(super) background_gradient: RoundUiGradientUniform,
+        pub(super) border_gradient: RoundUiGradientUniform,
+        pub(super) image: RoundUiImageUniform,
+        pub(super) outline: RoundUiOutline,
+        pub(super) border_style: RoundUiBorderStyleUniform,
+        pub(super) stroke_color: LinearRgba,
+        pub(super) stroke_width: f32,
+        pub(super) fill_color: LinearRgba,
+        pub(super) fill_fraction: f32,
+        pub(super) fill_direction: u32,
+        pub(super) sweep_mask: RoundUiSweepMaskUniform,
+        pub(super) offset: Vec4,
+    }
+}
+
+impl AsBindGroupShaderType<PolygonUiMaterialUniform> for PolygonUiMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> PolygonUiMaterialUniform {
+        PolygonUiMaterialUniform {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            border_thickness: self.border_thickness,
+            points: self.points.max(3),
+            inner_radius: self.inner_radius,
+            rotation: self.rotation,
+            corner_radius: self.corner_radius,
+            edge_softness: self.edge_softness,
+            shadow: self.shadow,
+            inset_shadow: self.inset_shadow,
+            background_gradient: self.background_gradient.as_ref().into(),
+            border_gradient: self.border_gradient.as_ref().into(),
+            image: RoundUiImageUniform::new(self.image.as_ref(), self.image_fit, self.image_tint),
+            outline: self.outline,
+            border_style: self.border_style.into(),
+            stroke_color: self.stroke_color,
+            stroke_width: self.stroke_width,
+            fill_color: self.fill_color,
+            fill_fraction: self.fill_fraction,
+            fill_direction: self.fill_direction.into(),
+            sweep_mask: self.sweep_mask.into(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl UiMaterial for PolygonUiMaterial {
+    fn vertex_shader() -> ShaderRef {
+        POLYGON_SHADER_HANDLE.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        POLYGON_SHADER_HANDLE.into()
+    }
+}
+
+impl RoundUiResponsiveMaterial for PolygonUiMaterial {
+    /// The `corner_radius` of every point, in the doubled units of `RoundUiBorder`
+    fn border_radius(&self) -> Vec4 {
+        Vec4::splat(self.corner_radius * 2.)
+    }
+
+    /// Sets the `corner_radius` from the largest corner, since every point has the same radius
+    fn set_border_radius(&mut self, border_radius: Vec4) {
+        self.corner_radius = border_radius.max_element() * 0.5;
+    }
+
+    fn offset(&self) -> Vec4 {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Vec4) {
+        self.offset = offset;
+    }
+
+    fn border_thickness(&self) -> Option<Vec4> {
+        Some(Vec4::splat(self.border_thickness))
+    }
+
+    /// Sets the `border_thickness` from the thickest side, since the border is even all the way around
+    fn set_border_thickness(&mut self, border_thickness: Vec4) {
+        self.border_thickness = border_thickness.max_element();
+    }
+}
+
+impl RoundUiSweepMaskMaterial for PolygonUiMaterial {
+    fn sweep_mask(&self) -> RoundUiSweepMask {
+        self.sweep_mask
+    }
+
+    fn set_sweep_mask(&mut self, sweep_mask: RoundUiSweepMask) {
+        self.sweep_mask = sweep_mask;
+    }
+}
+
+impl RoundUiTransitionMaterial for PolygonUiMaterial {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            background_color: mix_color(self.background_color, target.background_color, t),
+            border_color: mix_color(self.border_color, target.border_color, t),
+            image_tint: mix_color(self.image_tint, target.image_tint, t),
+            border_thickness: mix_f32(self.border_thickness, target.border_thickness, t),
+            offset: self.offset.lerp(target.offset, t),
+            inner_radius: mix_f32(self.inner_radius, target.inner_radius, t),
+            rotation: mix_f32(self.rotation, target.rotation, t),
+            corner_radius: mix_f32(self.corner_radius, target.corner_radius, t),
+            stroke_color: mix_color(self.stroke_color, target.stroke_color, t),
+            stroke_width: mix_f32(self.stroke_width, target.stroke_width, t),
+            edge_softness: mix_f32(self.edge_softness, target.edge_softness, t),
+            shadow: mix_shadow(self.shadow, target.shadow, t),
+            inset_shadow: mix_shadow(self.inset_shadow, target.inset_shadow, t),
+            outline: mix_outline(self.outline, target.outline, t),
+            fill_color: mix_color(self.fill_color, target.fill_color, t),
+            fill_fraction: mix_f32(self.fill_fraction, target.fill_fraction, t),
+            ..target.clone()
+        }
+    }
+}
+
+impl RoundUiPaddedMaterial for PolygonUiMaterial {
+    /// Pads each side by its offset and the border thickness. The `Corners` mode also pads by the `corner_radius`, but
+    /// doesn't keep content clear of the points of the shape, which depend on the size of the node.
+    fn padding(&self, mode: RoundUiPaddingMode) -> RoundUiOffset {
+        let offset: RoundUiOffset = self.offset.into();
+        let offset = offset + RoundUiOffset::all(self.border_thickness);
+        match mode {
+            RoundUiPaddingMode::Offset => offset,
+            RoundUiPaddingMode::Corners => offset + RoundUiOffset::all(self.corner_radius),
+        }
+    }
+}
+
+impl PolygonUiMaterial {
+    pub fn get_padding(&self) -> UiRect {
+        self.padding(RoundUiPaddingMode::Corners).into()
+    }
+}
@@ -0,0 +1,286 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+#import bevy_render::maths::PI
+#import bevy_round_ui::shared::{
+    RoundUiBorderStyle,
+    RoundUiGradient,
+    RoundUiImage,
+    RoundUiOutline,
+    RoundUiShadow,
+    RoundUiSweepMask,
+    blend_over,
+    border_style_coverage,
+    coverage,
+    dither,
+    edge_width,
+    expand_vertex,
+    fill_color,
+    image_color,
+    inset_shadow_coverage,
+    outline_color,
+    outline_extents,
+    premultiply,
+    progress_rect,
+    sdf_rounded_rect,
+    shadow_coverage,
+    shadow_extents,
+    sweep_mask_color,
+    unpremultiply,
+}
+
+struct PolygonUiMaterial {
+    /// Background color
+    @location(0) background_color: vec4<f32>,
+    /// Border color
+    @location(1) border_color: vec4<f32>,
+    /// Border thickness in pixels
+    @location(2) border_thickness: f32,
+    /// Number of points (or sides of a regular polygon)
+    @location(3) points: u32,
+    /// Distance of the inner vertices from the center, relative to the points
+    @location(4) inner_radius: f32,
+    /// Rotation of the shape in radians, clockwise
+    @location(5) rotation: f32,
+    /// Radius of the rounded points, in pixels
+    @location(6) corner_radius: f32,
+    /// Width of the anti-aliased edge, in pixels
+    @location(7) edge_softness: f32,
+    /// Drop shadow drawn behind the shape
+    @location(8) shadow: RoundUiShadow,
+    /// Inner shadow drawn inside the border
+    @location(9) inset_shadow: RoundUiShadow,
+    /// Gradients used instead of `background_color` and `border_color`
+    @location(10) background_gradient: RoundUiGradient,
+    @location(11) border_gradient: RoundUiGradient,
+    /// Image drawn on top of the background
+    @location(12) image: RoundUiImage,
+    /// Outline drawn outside the shape
+    @location(13) outline: RoundUiOutline,
+    /// Solid, dashed or dotted border
+    @location(14) border_style: RoundUiBorderStyle,
+    /// Stroke drawn just inside the outer edge of the shape
+    @location(15) stroke_color: vec4<f32>,
+    @location(16) stroke_width: f32,
+    /// Progress fill drawn over the background: `fill_color`, `fill_fraction`
+    /// and `fill_direction`, renamed so they don't clash with the imported
+    /// `fill_color` function
+    @location(17) progress_color: vec4<f32>,
+    @location(18) progress_fraction: f32,
+    /// Side the fill starts from, matching `RoundUiFillDirection`
+    @location(19) progress_direction: u32,
+    /// "Clock wipe" overlay drawn over the shape
+    @location(20) sweep_mask: RoundUiSweepMask,
+    /// Offset of each side of the face in pixels: (top, left, bottom, right)
+    @location(21) offset: vec4<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> input: PolygonUiMaterial;
+
+@group(1) @binding(1)
+var image_texture: texture_2d<f32>;
+@group(1) @binding(2)
+var image_sampler: sampler;
+
+/// Signed distance from `p` to a star with `n` points reaching `r` pixels
+/// from the center, and inner vertices `r_in` pixels from the center. The
+/// first point is straight up, along the positive y axis.
+///
+/// A star whose inner vertices lie on the sides of the regular polygon
+/// through its points is that polygon.
+fn sd_star(p: vec2f, n: f32, r: f32, r_in: f32) -> f32 {
+    // fold `p` into the wedge between the first point and the next inner
+    // vertex, `a` radians clockwise from it
+    let a = PI / n;
+    let angle = atan2(p.x, p.y);
+    let folded = abs(angle - 2. * a * round(angle / (2. * a)));
+    let q = length(p) * vec2f(sin(folded), cos(folded));
+
+    // distance to the side between the two vertices, negative on the side of
+    // the center
+    let tip = vec2f(0., r);
+    let side = r_in * vec2f(sin(a), cos(a)) - tip;
+    let w = q - tip;
+    let h = clamp(dot(w, side) / dot(side, side), 0., 1.);
+    let d = length(w - side * h);
+    return select(d, -d, side.x * w.y - side.y * w.x < 0.);
+}
+
+/// `p`, relative to the center of the node with y pointing down, in the space
+/// of the unrotated shape, with y pointing up.
+fn shape_space(p: vec2f) -> vec2f {
+    // rotate the shape clockwise, flipping y so it points up
+    let up = vec2f(p.x, -p.y);
+    let c = cos(input.rotation);
+    let s = sin(input.rotation);
+    return vec2f(up.x * c - up.y * s, up.x * s + up.y * c);
+}
+
+/// Signed distance, in pixels, to the shape filling a node of `size` pixels,
+/// where `p` is relative to the center of the node with y pointing down.
+fn sd_polygon(p: vec2f, size: vec2f) -> f32 {
+    let q = shape_space(p);
+    let n = f32(max(input.points, 3u));
+    let a = PI / n;
+    let r = 0.5 * min(size.x, size.y);
+    // the inner vertices can't be further out than the sides of the polygon
+    let ratio = clamp(input.inner_radius, 0., cos(a));
+
+    // Rounding grows a shape scaled towards the center, whose sides are
+    // parallel to the sides of the original shape and `corner_radius` pixels
+    // inside them, so the points are rounded without changing the sides.
+    let side = ratio * vec2f(sin(a), cos(a)) - vec2f(0., 1.);
+    let apothem = r * side.x / length(side);
+    let radius = clamp(input.corner_radius, 0., apothem);
+    let scale = select(1., max(1. - radius / apothem, 0.0001), apothem > 0.);
+    return sd_star(q, n, r * scale, r * scale * ratio) - radius;
+}
+
+/// Position along the outline of the shape, `inset` pixels inside its edge,
+/// for dashed and dotted borders.
+///
+/// Returns `(position, perimeter)` in pixels, like `perimeter_position`. The
+/// position is measured clockwise from the first point, and is spread evenly
+/// along the sides, so the rounded points are not measured separately.
+fn polygon_perimeter_position(p: vec2f, size: vec2f, inset: f32) -> vec2f {
+    let q = shape_space(p);
+    let n = f32(max(input.points, 3u));
+    let a = PI / n;
+    let r = 0.5 * min(size.x, size.y);
+    let ratio = clamp(input.inner_radius, 0., cos(a));
+
+    // The outline has `2 * n` sides of the same length, from each point to
+    // the next inner vertex and on to the next point. Moving the outline
+    // inwards shrinks it like scaling it towards the center.
+    let side = ratio * vec2f(sin(a), cos(a)) - vec2f(0., 1.);
+    let apothem = r * side.x / length(side);
+    let side_length = r * length(side) * max(1. - inset / max(apothem, 0.0001), 0.);
+
+    // the side containing `q`, counted clockwise from the first point
+    let angle = atan2(q.x, q.y);
+    let turn = select(angle, angle + 2. * PI, angle < 0.);
+    let index = min(floor(turn / a), 2. * n - 1.);
+
+    // how far `q` is along the side, from the point at its end
+    let folded = abs(angle - 2. * a * round(angle / (2. * a)));
+    let w = length(q) * vec2f(sin(folded), cos(folded)) - vec2f(0., r);
+    let h = clamp(dot(w, r * side) / dot(r * side, r * side), 0., 1.);
+    let along = select(1. - h, h, index % 2. == 0.);
+    return vec2f((index + along) * side_length, 2. * n * side_length);
+}
+
+@vertex
+fn vertex(
+    @location(0) vertex_position: vec3<f32>,
+    @location(1) vertex_uv: vec2<f32>,
+    @location(2) size: vec2<f32>,
+    @location(3) border_widths: vec4<f32>,
+) -> UiVertexOutput {
+    // grow the quad so the shadow and outline can be drawn outside the node
+    return expand_vertex(
+        vertex_position,
+        vertex_uv,
+        size,
+        border_widths,
+        max(shadow_extents(input.shadow), outline_extents(input.outline)),
+    );
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // adjust UVs around the middle of the node, in pixels
+    let p = (in.uv - 0.5) * in.size;
+
+    // The face is the shape filling the node shrunk by the offset, drawn over
+    // a base of the border color filling the whole node. Everything inside
+    // the face is positioned relative to it.
+    let offset = max(input.offset, vec4f(0.));
+    let has_offset = any(offset > vec4f(0.));
+    let face_center = 0.5 * vec2f(offset.y - offset.w, offset.x - offset.z);
+    let face_size = in.size - vec2f(offset.y + offset.w, offset.x + offset.z);
+    let fp = p - face_center;
+
+    let d_face = sd_polygon(fp, face_size);
+    let d = min(d_face, sd_polygon(p, in.size));
+    let thickness = max(input.border_thickness, 0.);
+
+    let aa = edge_width(d, input.edge_softness);
+
+    // Coverage of the whole shape, and of the area inside the border.
+    let alpha = coverage(d, aa);
+    var inner = 1.;
+    if thickness > 0. || has_offset {
+        inner = coverage(d_face + thickness, aa);
+    }
+
+    // Draw the image and the inset shadow on top of the background, inside the
+    // border.
+    var background = fill_color(input.background_color, input.background_gradient, p, in.size);
+    if input.image.fit != 0u {
+        // fit the image into the face, inside the border
+        let inner_p = fp + 0.5 * face_size - thickness;
+        let inner_size = face_size - 2. * thickness;
+        let image = image_color(image_texture, image_sampler, input.image, inner_p, inner_size);
+        background = blend_over(image, background);
+    }
+    if input.progress_fraction > 0. && input.progress_color.a > 0. {
+        // The fill covers a fraction of the circle the face fills, clipped to
+        // the area inside the border.
+        let r = 0.5 * min(face_size.x, face_size.y);
+        let fill = progress_rect(vec2f(r), input.progress_fraction, input.progress_direction);
+        let d_fill = max(sdf_rounded_rect(fp - fill.xy, fill.zw, vec4f(0.)), d_face + thickness);
+        background = blend_over(premultiply(input.progress_color) * coverage(d_fill, aa), background);
+    }
+    if input.inset_shadow.color.a > 0. {
+        // the hole follows the inner edge of the border
+        let d_hole = sd_polygon(fp - input.inset_shadow.offset, face_size)
+            + thickness
+            + input.inset_shadow.spread;
+        let inset = premultiply(input.inset_shadow.color)
+            * inset_shadow_coverage(d_hole, input.inset_shadow.blur, aa);
+        background = blend_over(inset, background);
+    }
+
+    // Blend between the border and background colors using premultiplied
+    // alpha, so a transparent color doesn't darken the seam.
+    let border = fill_color(input.border_color, input.border_gradient, p, in.size);
+    var dashes = 1.;
+    if input.border_style.style != 0u && thickness > 0. {
+        // dashes and dots follow the center line of the border around the face
+        let half_thickness = 0.5 * thickness;
+        let path = polygon_perimeter_position(fp, face_size, half_thickness);
+        dashes = border_style_coverage(input.border_style, path.x, path.y, d_face + half_thickness, thickness, aa);
+    }
+    var color = mix(border * dashes, background, inner) * alpha;
+
+    // Draw the stroke on top, along the inside of the outer edge.
+    if input.stroke_width > 0. && input.stroke_color.a > 0. {
+        let stroke = alpha * (1. - coverage(d + input.stroke_width, aa));
+        color = blend_over(premultiply(input.stroke_color) * stroke, color);
+    }
+
+    // Draw the sweep mask over the whole shape, sweeping around the center of
+    // the face.
+    color = blend_over(sweep_mask_color(input.sweep_mask, fp, aa) * alpha, color);
+
+    // Draw the shadow behind the shape. Like CSS box shadows, it is hidden
+    // underneath the shape even if the shape is transparent.
+    var shadow = vec4f(0.);
+    if input.shadow.color.a > 0. {
+        let shadow_p = p - input.shadow.offset;
+        let d_shadow = min(
+            sd_polygon(shadow_p - face_center, face_size),
+            sd_polygon(shadow_p, in.size),
+        ) - input.shadow.spread;
+        shadow = premultiply(input.shadow.color)
+            * shadow_coverage(d_shadow, input.shadow.blur, aa)
+            * (1. - alpha);
+    }
+
+    // Draw the outline on top of everything else.
+    let outline = outline_color(input.outline, d, aa);
+    var result = unpremultiply(blend_over(outline, color + shadow));
+    if input.background_gradient.count > 0u || input.border_gradient.count > 0u {
+        result = dither(result, in.position.xy);
+    }
+    return result;
+}
@@ -0,0 +1,187 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::types::*;
+
+/// Responsive border radius and offset for a material node, resolved against the computed size of the node and the
+/// size of the primary window every frame.
+///
+/// The resolved values are written to the material of the node. Fields that are `None` are left untouched, and the
+/// `border_thickness` is ignored by materials without one. `PolygonUiMaterial` takes its `corner_radius` from the
+/// largest corner and its `border_thickness` from the thickest side.
+///
+/// Nodes with this component should each have their own material, since nodes sharing a material would overwrite
+/// each other's values.
+///
+/// The values are resolved after the layout, since they depend on the size of the node, so a `RoundUiAutoPadding` on
+/// the same node lags a frame behind them.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// let responsive = RoundUiResponsive {
+///     border_radius: Some(RoundUiBorderVal::all(RoundUiVal::Percent(25.))),
+///     offset: Some(RoundUiOffsetVal::all(RoundUiVal::VMin(1.))),
+///     border_thickness: None,
+/// };
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+pub struct RoundUiResponsive {
+    /// The radius of each corner, resolved to the units of `RoundUiBorder`
+    pub border_radius: Option<RoundUiBorderVal>,
+    /// The offset along each side
+    pub offset: Option<RoundUiOffsetVal>,
+    /// The border thickness along each side, for `SuperellipseUiMaterial` and `PolygonUiMaterial`
+    pub border_thickness: Option<RoundUiOffsetVal>,
+}
+
+/// A material with a `border_radius` and an `offset`, and optionally a per-side `border_thickness`, that
+/// `RoundUiResponsive` resolves to.
+pub(crate) trait RoundUiResponsiveMaterial: UiMaterial {
+    fn border_radius(&self) -> Vec4;
+
+    fn set_border_radius(&mut self, border_radius: Vec4);
+
+    fn offset(&self) -> Vec4;
+
+    fn set_offset(&mut self, offset: Vec4);
+
+    /// The border thickness along each side, or `None` if the material doesn't have one
+    fn border_thickness(&self) -> Option<Vec4> {
+        None
+    }
+
+    fn set_border_thickness(&mut self, _border_thickness: Vec4) {}
+}
+
+/// Adds the system resolving `RoundUiResponsive` components for the material `M`.
+pub(crate) fn add_responsive_systems<M: RoundUiResponsiveMaterial>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        resolve_responsive_units::<M>.in_set(RoundUiSystem::Responsive),
+    );
+}
+
+/// Writes the resolved `RoundUiResponsive` values to the materials of each node, after the layout has been computed.
+///
+/// Materials are only modified when a value changes, so they are not re-uploaded to the GPU every frame.
+fn resolve_responsive_units<M: RoundUiResponsiveMaterial>(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    query: Query<(&Node, &RoundUiResponsive, &Handle<M>)>,
+    mut materials: ResMut<Assets<M>>,
+) {
+    let viewport_size = windows
+        .get_single()
+        .map(|window| window.size())
+        .unwrap_or(Vec2::ZERO);
+
+    for (node, responsive, handle) in query.iter() {
+        let Some(material) = materials.get(handle) else {
+            continue;
+        };
+        let node_size = node.size();
+
+        let border_radius: Option<Vec4> = responsive
+            .border_radius
+            .map(|border_radius| border_radius.resolve(node_size, viewport_size).into());
+        let offset: Option<Vec4> = responsive
+            .offset
+            .map(|offset| offset.resolve(node_size, viewport_size).into());
+        let border_thickness: Option<Vec4> = responsive
+            .border_thickness
+            .filter(|_| material.border_thickness().is_some())
+            .map(|border_thickness| border_thickness.resolve(node_size, viewport_size).into());
+
+        let border_radius_changed =
+            border_radius.is_some_and(|value| value != material.border_radius());
+        let offset_changed = offset.is_some_and(|value| value != material.offset());
+        let border_thickness_changed =
+            border_thickness.is_some_and(|value| Some(value) != material.border_thickness());
+        if !border_radius_changed && !offset_changed && !border_thickness_changed {
+            continue;
+        }
+
+        let Some(material) = materials.get_mut(handle) else {
+            continue;
+        };
+        if let Some(border_radius) = border_radius {
+            material.set_border_radius(border_radius);
+        }
+        if let Some(offset) = offset {
+            material.set_offset(offset);
+        }
+        if let Some(border_thickness) = border_thickness {
+            material.set_border_thickness(border_thickness);
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "superellipse", feature = "polygon")))]
+mod test {
+    use super::*;
+    use crate::test_util::*;
+
+    #[cfg(feature = "superellipse")]
+    #[test]
+    fn superellipse_resolves_offset_and_border_thickness() {
+        use crate::superellipse::SuperellipseUiMaterial;
+
+        let mut app = material_test_app::<SuperellipseUiMaterial>();
+        app.add_systems(Update, resolve_responsive_units::<SuperellipseUiMaterial>);
+        let handle = add_material(&mut app, SuperellipseUiMaterial::default());
+        let entity = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                RoundUiResponsive {
+                    border_radius: None,
+                    offset: Some(RoundUiOffsetVal {
+                        bottom: RoundUiVal::Px(6.),
+                        ..default()
+                    }),
+                    border_thickness: Some(RoundUiOffsetVal::all(RoundUiVal::Px(2.))),
+                },
+                handle,
+            ))
+            .id();
+        app.update();
+
+        let handle = material_handle::<SuperellipseUiMaterial>(&app, entity);
+        let material = material(&app, &handle);
+        assert_eq!(material.offset, RoundUiOffset::bottom(6.).into());
+        assert_eq!(material.border_thickness, RoundUiOffset::all(2.).into());
+    }
+
+    #[cfg(feature = "polygon")]
+    #[test]
+    fn polygon_resolves_corner_radius_and_border_thickness() {
+        use crate::polygon::PolygonUiMaterial;
+
+        let mut app = material_test_app::<PolygonUiMaterial>();
+        app.add_systems(Update, resolve_responsive_units::<PolygonUiMaterial>);
+        let handle = add_material(&mut app, PolygonUiMaterial::default());
+        let entity = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                RoundUiResponsive {
+                    border_radius: Some(RoundUiBorderVal::all(RoundUiVal::Px(4.))),
+                    offset: None,
+                    border_thickness: Some(RoundUiOffsetVal {
+                        top: RoundUiVal::Px(3.),
+                        ..RoundUiOffsetVal::all(RoundUiVal::Px(1.))
+                    }),
+                },
+                handle,
+            ))
+            .id();
+        app.update();
+
+        let handle = material_handle::<PolygonUiMaterial>(&app, entity);
+        let material = material(&app, &handle);
+        assert_eq!(material.corner_radius, 4.);
+        assert_eq!(material.border_thickness, 3.);
+    }
+}
@@ -1,6 +1,27 @@
-use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::*};
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    render::{render_asset::RenderAssets, render_resource::*, texture::GpuImage},
+};
 
-use crate::types::*;
+use crate::{
+    cooldown::{add_cooldown_systems, RoundUiSweepMaskMaterial},
+    focus::RoundUiFocusRingMaterial,
+    interaction::add_interaction_systems,
+    managed::{add_managed_style_systems, color_components, float_bits, RoundUiManagedStyle},
+    padding::{add_padding_systems, RoundUiPaddedMaterial, RoundUiPaddingMode},
+    responsive::{add_responsive_systems, RoundUiResponsiveMaterial},
+    round_rect_instanced::RoundRectInstancePlugin,
+    shared::{
+        configure_system_sets, load_shared_shaders, RoundUiBorderStyleUniform,
+        RoundUiGradientUniform, RoundUiImageUniform, RoundUiSweepMaskUniform,
+    },
+    transition::{
+        add_transition_systems, mix_color, mix_f32, mix_outline, mix_shadow,
+        RoundUiTransitionMaterial,
+    },
+    types::*,
+};
 
 pub const ROUND_RECT_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(66552904175742639684);
 
@@ -9,6 +30,8 @@ pub struct RoundRectMaterialPlugin;
 
 impl Plugin for RoundRectMaterialPlugin {
     fn build(&self, app: &mut App) {
+        load_shared_shaders(app);
+        configure_system_sets(app);
         load_internal_asset!(
             app,
             ROUND_RECT_SHADER_HANDLE,
@@ -16,59 +39,362 @@ impl Plugin for RoundRectMaterialPlugin {
             Shader::from_wgsl
         );
 
-        app.add_plugins(UiMaterialPlugin::<RoundRectUiMaterial>::default());
+        app.add_plugins((
+            UiMaterialPlugin::<RoundRectUiMaterial>::default(),
+            RoundRectInstancePlugin,
+        ));
+        add_responsive_systems::<RoundRectUiMaterial>(app);
+        add_managed_style_systems::<RoundRectUiStyle>(app);
+        add_interaction_systems::<RoundRectUiMaterial>(app);
+        add_transition_systems::<RoundRectUiMaterial>(app);
+        add_cooldown_systems::<RoundRectUiMaterial>(app);
+        add_padding_systems::<RoundRectUiMaterial>(app);
     }
 }
 
 /// UI Material that renders a rounded rect with an optional offset color and position.
 #[derive(AsBindGroup, Asset, Debug, Clone, Reflect)]
+#[uniform(0, RoundRectUiMaterialUniform)]
 #[reflect(Default, Debug)]
 pub struct RoundRectUiMaterial {
     /// The background color of the material
-    #[uniform(0)]
     pub background_color: LinearRgba,
 
+    /// Gradient used for the background instead of `background_color`
+    pub background_gradient: Option<RoundUiGradient>,
+
+    /// Image drawn on top of the background, clipped to the shape. Render target images can be used to show the
+    /// output of a camera.
+    #[texture(1)]
+    #[sampler(2)]
+    pub image: Option<Handle<Image>>,
+
+    /// How the image is fitted into the inner button area
+    pub image_fit: RoundUiImageFit,
+
+    /// Color the image is multiplied by
+    pub image_tint: LinearRgba,
+
     /// The border color of the material
-    #[uniform(0)]
     pub border_color: LinearRgba,
 
+    /// Gradient used for the border (offset) area instead of `border_color`
+    pub border_gradient: Option<RoundUiGradient>,
+
+    /// Whether the border is solid, dashed or dotted. Dots are as wide as the thinnest side of the `offset`.
+    pub border_style: RoundUiBorderStyle,
+
     /// The border radius of each corner
     /// E.g. Vec4::new(bottom_right, top_right, bottom_left, top_left)
-    #[uniform(0)]
+    ///
+    /// Each round corner is a circular arc with a radius of half its value, in pixels.
     pub border_radius: Vec4,
 
+    /// The shape of each corner, sized by its `border_radius`
+    pub corners: RoundUiCorners,
+
+    /// Figma-style smoothing of the round corners, from 0 (circular corners) to 1 (fully smoothed).
+    ///
+    /// Smoothed corners ease into the sides with a continuous curvature, reaching up to `1 + corner_smoothing` times
+    /// their radius along each side. The smoothing is reduced when the node is too small to fit it.
+    pub corner_smoothing: f32,
+
     /// The border offset along each side of the rect
     /// E.g. Vec4::new((top, left, bottom, right)
-    #[uniform(0)]
     pub offset: Vec4,
+
+    /// Color of the stroke drawn along the outer edge of the shape
+    pub stroke_color: LinearRgba,
+
+    /// Width of the stroke drawn along the outer edge of the shape, in pixels.
+    ///
+    /// The stroke is drawn on top of the shape, just inside its outer edge, so it outlines both the inner button area
+    /// and the offset area without changing either of them.
+    pub stroke_width: f32,
+
+    /// Width of the anti-aliased edges of the shape, in pixels. See [edge softness](crate#edge-softness).
+    pub edge_softness: f32,
+
+    /// Drop shadow drawn behind the material. It is rendered outside the bounds of the node if needed.
+    pub shadow: RoundUiShadow,
+
+    /// Inner shadow drawn inside the inner button area, on top of the background.
+    pub inset_shadow: RoundUiShadow,
+
+    /// Outline drawn around the outside of the material, following its corner radii. It is rendered outside the bounds
+    /// of the node, so it doesn't affect the layout.
+    pub outline: RoundUiOutline,
+
+    /// Color of the progress fill, drawn over the background
+    pub fill_color: LinearRgba,
+
+    /// How much of the inner button area the progress fill covers, from `0.0` (empty) to `1.0` (full), e.g. for health bars.
+    ///
+    /// The fill has rounded corners of its own, so its leading edge keeps a rounded cap, and it is clipped to the shape
+    /// so it stays inside it at any fraction.
+    pub fill_fraction: f32,
+
+    /// The side the progress fill starts from
+    pub fill_direction: RoundUiFillDirection,
+
+    /// "Clock wipe" overlay drawn over the material and clipped to its shape, e.g. for the cooldown of an ability
+    /// button. It sweeps around the center of the inner button area.
+    pub sweep_mask: RoundUiSweepMask,
 }
 
 impl Default for RoundRectUiMaterial {
     fn default() -> Self {
         Self {
             background_color: LinearRgba::WHITE,
+            background_gradient: None,
+            image: None,
+            image_fit: RoundUiImageFit::default(),
+            image_tint: LinearRgba::WHITE,
             border_color: LinearRgba::NONE,
+            border_gradient: None,
+            border_style: RoundUiBorderStyle::default(),
             border_radius: Vec4::splat(0.),
+            corners: RoundUiCorners::default(),
+            corner_smoothing: 0.,
             offset: Vec4::splat(0.),
+            stroke_color: LinearRgba::NONE,
+            stroke_width: 0.,
+            edge_softness: 0.,
+            shadow: RoundUiShadow::default(),
+            inset_shadow: RoundUiShadow::default(),
+            outline: RoundUiOutline::default(),
+            fill_color: LinearRgba::NONE,
+            fill_fraction: 0.,
+            fill_direction: RoundUiFillDirection::default(),
+            sweep_mask: RoundUiSweepMask::default(),
+        }
+    }
+}
+
+pub use shader_types::RoundRectUiMaterialUniform;
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// The GPU representation of a `RoundRectUiMaterial`.
+    #[derive(Clone, Default, ShaderType)]
+    pub struct RoundRectUiMaterialUniform {
+        pub(super) background_color: LinearRgba,
+        pub(super) border_color: LinearRgba,
+        pub(super) border_radius: Vec4,
+        pub(super) offset: Vec4,
+        pub(super) edge_softness: f32,
+        pub(super) shadow: RoundUiShadow,
+        pub(super) inset_shadow: RoundUiShadow,
+        pub(super) background_gradient: RoundUiGradientUniform,
+        pub(super) border_gradient: RoundUiGradientUniform,
+        pub(super) image: RoundUiImageUniform,
+        pub(super) border_style: RoundUiBorderStyleUniform,
+        pub(super) corners: UVec4,
+        pub(super) corner_smoothing: f32,
+        pub(super) stroke_color: LinearRgba,
+        pub(super) stroke_width: f32,
+        pub(super) outline: RoundUiOutline,
+        pub(super) fill_color: LinearRgba,
+        pub(super) fill_fraction: f32,
+        pub(super) fill_direction: u32,
+        pub(super) sweep_mask: RoundUiSweepMaskUniform,
+    }
+}
+
+impl AsBindGroupShaderType<RoundRectUiMaterialUniform> for RoundRectUiMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> RoundRectUiMaterialUniform {
+        RoundRectUiMaterialUniform {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            border_radius: self.border_radius,
+            offset: self.offset,
+            edge_softness: self.edge_softness,
+            shadow: self.shadow,
+            inset_shadow: self.inset_shadow,
+            background_gradient: self.background_gradient.as_ref().into(),
+            border_gradient: self.border_gradient.as_ref().into(),
+            image: RoundUiImageUniform::new(self.image.as_ref(), self.image_fit, self.image_tint),
+            border_style: self.border_style.into(),
+            corners: self.corners.into(),
+            corner_smoothing: self.corner_smoothing.clamp(0., 1.),
+            stroke_color: self.stroke_color,
+            stroke_width: self.stroke_width,
+            outline: self.outline,
+            fill_color: self.fill_color,
+            fill_fraction: self.fill_fraction,
+            fill_direction: self.fill_direction.into(),
+            sweep_mask: self.sweep_mask.into(),
         }
     }
 }
 
 impl UiMaterial for RoundRectUiMaterial {
+    fn vertex_shader() -> ShaderRef {
+        ROUND_RECT_SHADER_HANDLE.into()
+    }
+
     fn fragment_shader() -> ShaderRef {
         ROUND_RECT_SHADER_HANDLE.into()
     }
 }
 
+impl RoundUiResponsiveMaterial for RoundRectUiMaterial {
+    fn border_radius(&self) -> Vec4 {
+        self.border_radius
+    }
+
+    fn set_border_radius(&mut self, border_radius: Vec4) {
+        self.border_radius = border_radius;
+    }
+
+    fn offset(&self) -> Vec4 {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Vec4) {
+        self.offset = offset;
+    }
+}
+
+impl RoundUiSweepMaskMaterial for RoundRectUiMaterial {
+    fn sweep_mask(&self) -> RoundUiSweepMask {
+        self.sweep_mask
+    }
+
+    fn set_sweep_mask(&mut self, sweep_mask: RoundUiSweepMask) {
+        self.sweep_mask = sweep_mask;
+    }
+}
+
+impl RoundUiTransitionMaterial for RoundRectUiMaterial {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            background_color: mix_color(self.background_color, target.background_color, t),
+            border_color: mix_color(self.border_color, target.border_color, t),
+            image_tint: mix_color(self.image_tint, target.image_tint, t),
+            border_radius: self.border_radius.lerp(target.border_radius, t),
+            offset: self.offset.lerp(target.offset, t),
+            stroke_color: mix_color(self.stroke_color, target.stroke_color, t),
+            stroke_width: mix_f32(self.stroke_width, target.stroke_width, t),
+            corner_smoothing: mix_f32(self.corner_smoothing, target.corner_smoothing, t),
+            edge_softness: mix_f32(self.edge_softness, target.edge_softness, t),
+            shadow: mix_shadow(self.shadow, target.shadow, t),
+            inset_shadow: mix_shadow(self.inset_shadow, target.inset_shadow, t),
+            outline: mix_outline(self.outline, target.outline, t),
+            fill_color: mix_color(self.fill_color, target.fill_color, t),
+            fill_fraction: mix_f32(self.fill_fraction, target.fill_fraction, t),
+            ..target.clone()
+        }
+    }
+}
+
+impl RoundUiFocusRingMaterial for RoundRectUiMaterial {
+    fn focus_ring_radius(&self) -> Vec4 {
+        self.border_radius
+    }
+}
+
+impl RoundUiPaddedMaterial for RoundRectUiMaterial {
+    fn padding(&self, mode: RoundUiPaddingMode) -> RoundUiOffset {
+        // the stroke only covers the inner button area where it is wider than the offset
+        let offset: RoundUiOffset = self.offset.max(Vec4::splat(self.stroke_width)).into();
+        match mode {
+            RoundUiPaddingMode::Offset => offset,
+            RoundUiPaddingMode::Corners => {
+                // smoothed corners reach further along the sides
+                let border: RoundUiBorder =
+                    (self.border_radius * (1. + self.corner_smoothing.clamp(0., 1.))).into();
+                RoundUiOffset {
+                    left: offset.left + border.top_left.max(border.bottom_left),
+                    right: offset.right + border.top_right.max(border.bottom_right),
+                    top: offset.top + border.top_left.max(border.top_right),
+                    bottom: offset.bottom + border.bottom_left.max(border.bottom_right),
+                }
+            }
+        }
+    }
+}
+
 impl RoundRectUiMaterial {
     pub fn get_padding(&self) -> UiRect {
-        let offset: RoundUiOffset = self.offset.into();
-        let border: RoundUiBorder = self.border_radius.into();
-        UiRect {
-            left: Val::Px(offset.left + border.top_left.max(border.bottom_left)),
-            right: Val::Px(offset.right + border.top_right.max(border.bottom_right)),
-            top: Val::Px(offset.top + border.top_left.max(border.top_right)),
-            bottom: Val::Px(offset.bottom + border.bottom_left.max(border.bottom_right)),
+        self.padding(RoundUiPaddingMode::Corners).into()
+    }
+}
+
+/// A simple rounded rect description for a node, which the plugin turns into a `RoundRectUiMaterial`.
+///
+/// Nodes with identical styles share the same material, which is updated when the style changes and freed once no
+/// node uses it. The node doesn't need a material of its own, a `NodeBundle` is enough.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn spawn_card(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         RoundRectUiStyle {
+///             background_color: LinearRgba::WHITE,
+///             border_color: LinearRgba::BLACK,
+///             border_radius: RoundUiBorder::all(10.),
+///             offset: RoundUiOffset::bottom(5.),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct RoundRectUiStyle {
+    /// The background color of the material
+    pub background_color: LinearRgba,
+    /// The border color of the material
+    pub border_color: LinearRgba,
+    /// The border radius of each corner
+    pub border_radius: RoundUiBorder,
+    /// The border offset along each side of the rect
+    pub offset: RoundUiOffset,
+}
+
+impl Default for RoundRectUiStyle {
+    fn default() -> Self {
+        Self {
+            background_color: LinearRgba::WHITE,
+            border_color: LinearRgba::NONE,
+            border_radius: RoundUiBorder::default(),
+            offset: RoundUiOffset::default(),
+        }
+    }
+}
+
+impl RoundUiManagedStyle for RoundRectUiStyle {
+    type Material = RoundRectUiMaterial;
+    type Key = [u32; 16];
+
+    fn key(&self) -> Self::Key {
+        let border_radius: Vec4 = self.border_radius.into();
+        let offset: Vec4 = self.offset.into();
+        let mut key = [0.; 16];
+        key[0..4].copy_from_slice(&color_components(self.background_color));
+        key[4..8].copy_from_slice(&color_components(self.border_color));
+        key[8..12].copy_from_slice(&border_radius.to_array());
+        key[12..16].copy_from_slice(&offset.to_array());
+        float_bits(key)
+    }
+
+    fn material(&self) -> Self::Material {
+        RoundRectUiMaterial {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            border_radius: self.border_radius.into(),
+            offset: self.offset.into(),
+            ..default()
         }
     }
 }
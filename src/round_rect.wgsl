@@ -1,4 +1,33 @@
 #import bevy_ui::ui_vertex_output::UiVertexOutput
+#import bevy_round_ui::shared::{
+    RoundUiBorderStyle,
+    RoundUiGradient,
+    RoundUiImage,
+    RoundUiOutline,
+    RoundUiShadow,
+    RoundUiSweepMask,
+    blend_over,
+    border_style_coverage,
+    coverage,
+    dither,
+    edge_width,
+    expand_vertex,
+    fill_color,
+    image_color,
+    inset_shadow_coverage,
+    outline_color,
+    outline_extents,
+    perimeter_position,
+    premultiply,
+    progress_rect,
+    sdf_cornered_rect,
+    sdf_rounded_rect,
+    shadow_coverage,
+    shadow_extents,
+    sweep_mask_color,
+    thinnest_side,
+    unpremultiply,
+}
 
 struct RoundUiMaterial {
     @location(0) background_color: vec4<f32>,
@@ -8,63 +37,204 @@ struct RoundUiMaterial {
     @location(2) border_radius: vec4<f32>,
     /// border offset: (top, left, bottom, right)
     @location(3) offset: vec4<f32>,
+    /// width of the anti-aliased edge, in pixels
+    @location(4) edge_softness: f32,
+    /// drop shadow drawn behind the shape
+    @location(5) shadow: RoundUiShadow,
+    /// inner shadow drawn inside the inner button area
+    @location(6) inset_shadow: RoundUiShadow,
+    /// gradients used instead of `background_color` and `border_color`
+    @location(7) background_gradient: RoundUiGradient,
+    @location(8) border_gradient: RoundUiGradient,
+    /// image drawn on top of the background
+    @location(9) image: RoundUiImage,
+    /// solid, dashed or dotted border
+    @location(10) border_style: RoundUiBorderStyle,
+    /// shape of each corner, in the same order as `border_radius`
+    @location(11) corners: vec4<u32>,
+    /// figma-style smoothing of the round corners, from 0 to 1
+    @location(12) corner_smoothing: f32,
+    /// outline drawn just inside the outer edge of the shape
+    @location(13) stroke_color: vec4<f32>,
+    @location(14) stroke_width: f32,
+    /// outline drawn outside the shape
+    @location(15) outline: RoundUiOutline,
+    /// progress fill drawn over the background of the inner button area:
+    /// `fill_color`, `fill_fraction` and `fill_direction`, renamed so they
+    /// don't clash with the imported `fill_color` function
+    @location(16) progress_color: vec4<f32>,
+    @location(17) progress_fraction: f32,
+    /// side the fill starts from, matching `RoundUiFillDirection`
+    @location(18) progress_direction: u32,
+    /// "clock wipe" overlay drawn over the shape
+    @location(19) sweep_mask: RoundUiSweepMask,
 }
 
 @group(1) @binding(0)
 var<uniform> input: RoundUiMaterial;
 
-// MIT License. © 2023 Inigo Quilez, Munrocket
-// https://gist.github.com/munrocket/30e645d584b5300ee69295e54674b3e4
-// https://compute.toys/view/398
-fn sdf_rounded_rect(p: vec2f, b: vec2f, r: vec4f) -> f32 {
-    var x = r.x;
-    var y = r.y;
-    x = select(r.z, r.x, p.x > 0.);
-    y = select(r.w, r.y, p.x > 0.);
-    x = select(y, x, p.y > 0.);
-    let q = abs(p) - b + x;
-    return min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.))) - x;
-}
-
-@fragment
-fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
-    // adjust UVs around the middle of the rect, and convert to pixel
-    // coordinates
-    let uv = in.uv * in.size * 2.0 - in.size;
+@group(1) @binding(1)
+var image_texture: texture_2d<f32>;
+@group(1) @binding(2)
+var image_sampler: sampler;
 
+/// Signed distance, in pixels, to the inner button area.
+///
+/// `uv` is in the doubled pixel space used by the fragment shader.
+fn sd_inner(uv: vec2<f32>, size: vec2<f32>) -> f32 {
     // position offset to account for border
     let border_offset = vec2<f32>(
         input.offset.w - input.offset.y, // right - left
         input.offset.z - input.offset.x, // bottom - top
     );
 
-    // SDF distance in the inner button area
     // The inner button size is equal to actual size - offset size
-    let size = in.size - vec2<f32>(
+    let inner_size = size - vec2<f32>(
+        input.offset.y + input.offset.w, // left + right
+        input.offset.x + input.offset.z, // top + bottom
+    );
+
+    // The distance is computed in a space scaled by 2, so halve it to get a
+    // distance in pixels.
+    return sdf_cornered_rect(uv + border_offset, inner_size, input.border_radius, input.corners, input.corner_smoothing) * 0.5;
+}
+
+/// Signed distance, in pixels, to the progress fill: a rounded rect covering
+/// `progress_fraction` of the inner button area, so its leading edge has a
+/// rounded cap of its own. It is clipped to the inner button area, so it stays
+/// inside the shape at any fraction.
+fn sd_fill(uv: vec2<f32>, size: vec2<f32>) -> f32 {
+    let border_offset = vec2<f32>(
+        input.offset.w - input.offset.y, // right - left
+        input.offset.z - input.offset.x, // bottom - top
+    );
+    let inner_size = size - vec2<f32>(
         input.offset.y + input.offset.w, // left + right
         input.offset.x + input.offset.z, // top + bottom
     );
-    let d_shape = sdf_rounded_rect(
-        uv + border_offset,
+    let fill = progress_rect(inner_size, input.progress_fraction, input.progress_direction);
+    let radius = min(input.border_radius, vec4<f32>(min(fill.z, fill.w)));
+    let d_fill = sdf_rounded_rect(uv + border_offset - fill.xy, fill.zw, radius) * 0.5;
+    return max(d_fill, sd_inner(uv, size));
+}
+
+/// Signed distance, in pixels, to the outer shape: the union of the inner
+/// button area and the border area.
+fn sd_outer(uv: vec2<f32>, size: vec2<f32>) -> f32 {
+    let d_border = sdf_cornered_rect(uv, size, input.border_radius, input.corners, input.corner_smoothing) * 0.5;
+    return min(sd_inner(uv, size), d_border);
+}
+
+@vertex
+fn vertex(
+    @location(0) vertex_position: vec3<f32>,
+    @location(1) vertex_uv: vec2<f32>,
+    @location(2) size: vec2<f32>,
+    @location(3) border_widths: vec4<f32>,
+) -> UiVertexOutput {
+    // grow the quad so the shadow and outline can be drawn outside the node
+    return expand_vertex(
+        vertex_position,
+        vertex_uv,
         size,
-        input.border_radius,
+        border_widths,
+        max(shadow_extents(input.shadow), outline_extents(input.outline)),
     );
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // adjust UVs around the middle of the rect, and convert to pixel
+    // coordinates
+    let uv = in.uv * in.size * 2.0 - in.size;
+
+    // SDF distances in the inner button area, and in the whole shape
+    let d_shape_px = sd_inner(uv, in.size);
+    let d_outer_px = sd_outer(uv, in.size);
+
+    let aa = edge_width(d_outer_px, input.edge_softness);
+
+    // Coverage of the whole shape (button and border area), and coverage of
+    // the inner button area.
+    let alpha = coverage(d_outer_px, aa);
+    let inner = coverage(d_shape_px, aa);
+
+    // Draw the image and the inset shadow on top of the background of the
+    // inner button area, leaving the border area untouched.
+    let p = uv * 0.5;
+    var background = fill_color(input.background_color, input.background_gradient, p, in.size);
+    if input.image.fit != 0u {
+        // fit the image into the inner button area
+        let inner_size = in.size - vec2<f32>(
+            input.offset.y + input.offset.w, // left + right
+            input.offset.x + input.offset.z, // top + bottom
+        );
+        let inner_p = p + 0.5 * in.size - input.offset.yx;
+        let image = image_color(image_texture, image_sampler, input.image, inner_p, inner_size);
+        background = blend_over(image, background);
+    }
+    if input.progress_fraction > 0. && input.progress_color.a > 0. {
+        let fill = premultiply(input.progress_color) * coverage(sd_fill(uv, in.size), aa);
+        background = blend_over(fill, background);
+    }
+    if input.inset_shadow.color.a > 0. {
+        let d_hole = sd_inner(uv - input.inset_shadow.offset * 2.0, in.size) + input.inset_shadow.spread;
+        let inset = premultiply(input.inset_shadow.color)
+            * inset_shadow_coverage(d_hole, input.inset_shadow.blur, aa);
+        background = blend_over(inset, background);
+    }
+
+    // Blend between `input.border_color` and the background using
+    // premultiplied alpha, so a transparent border doesn't darken the seam.
+    let border = fill_color(input.border_color, input.border_gradient, p, in.size);
+    var dashes = 1.;
+    let thickness = thinnest_side(input.offset);
+    if input.border_style.style != 0u && thickness > 0. {
+        // Dashes and dots follow the center line of the thinnest side of the
+        // border. The distance field is in the doubled space, so the radii
+        // are halved.
+        let half_thickness = 0.5 * thickness;
+        let path = perimeter_position(
+            p,
+            0.5 * in.size - half_thickness,
+            0.5 * input.border_radius - half_thickness,
+            false,
+        );
+        let d_line = sdf_cornered_rect(uv, in.size, input.border_radius, input.corners, input.corner_smoothing) * 0.5 + half_thickness;
+        dashes = border_style_coverage(input.border_style, path.x, path.y, d_line, thickness, aa);
+    }
+    var color = mix(border * dashes, background, inner) * alpha;
+
+    // Draw the stroke on top, along the inside of the outer edge of the whole
+    // shape, independent of the offset.
+    if input.stroke_width > 0. && input.stroke_color.a > 0. {
+        let stroke = alpha * (1. - coverage(d_outer_px + input.stroke_width, aa));
+        color = blend_over(premultiply(input.stroke_color) * stroke, color);
+    }
 
-    // SDF distance in border area
-    let d_border = sdf_rounded_rect(uv, in.size, input.border_radius);
-
-    // define the alpha value. Opaque if within the button or border area,
-    // transparent otherwise.
-    let alpha = select(1., 0., (d_shape > 0. && d_border > 0.));
-    // define the final color. Use `input.border_color` if within border
-    // radius, otherwise `input.background_color`.
-    let color = select(
-        input.background_color,
-        input.border_color,
-        (d_shape > 0. && d_border <= 0.),
+    // Draw the sweep mask over the whole shape, sweeping around the center of
+    // the inner button area.
+    let inner_center = 0.5 * vec2<f32>(
+        input.offset.y - input.offset.w, // left - right
+        input.offset.x - input.offset.z, // top - bottom
     );
+    color = blend_over(sweep_mask_color(input.sweep_mask, p - inner_center, aa) * alpha, color);
 
-    // TODO: Add color smoothing
+    // Draw the shadow behind the shape. Like CSS box shadows, it is hidden
+    // underneath the shape even if the shape is transparent.
+    var shadow = vec4<f32>(0.);
+    if input.shadow.color.a > 0. {
+        let d_shadow = sd_outer(uv - input.shadow.offset * 2.0, in.size) - input.shadow.spread;
+        shadow = premultiply(input.shadow.color)
+            * shadow_coverage(d_shadow, input.shadow.blur, aa)
+            * (1. - alpha);
+    }
 
-    return vec4<f32>(color.rgb, alpha * color.a);
+    // Draw the outline on top of everything else.
+    let outline = outline_color(input.outline, d_outer_px, aa);
+    var result = unpremultiply(blend_over(outline, color + shadow));
+    if input.background_gradient.count > 0u || input.border_gradient.count > 0u {
+        result = dither(result, in.position.xy);
+    }
+    return result;
 }
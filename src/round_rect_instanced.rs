@@ -0,0 +1,491 @@
+use std::ops::Range;
+
+use bevy::{
+    asset::load_internal_asset,
+    ecs::{
+        entity::EntityHashMap,
+        system::{
+            lifetimeless::{Read, SRes},
+            SystemParamItem,
+        },
+    },
+    math::FloatOrd,
+    prelude::*,
+    render::{
+        render_phase::{
+            AddRenderCommand, DrawFunctions, PhaseItem, PhaseItemExtraIndex, RenderCommand,
+            RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewSortedRenderPhases,
+        },
+        render_resource::{binding_types::uniform_buffer, *},
+        renderer::{RenderDevice, RenderQueue},
+        texture::BevyDefault,
+        view::{ExtractedView, ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms},
+        Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+    },
+    ui::{CalculatedClip, DefaultUiCamera, RenderUiSystem, TransparentUi, UiStack},
+};
+
+use crate::{shared::load_shared_shaders, types::*};
+
+pub const ROUND_RECT_INSTANCED_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(84130925127702153391);
+
+/// Plugin which draws `RoundRectUiInstance` nodes. It is added by `RoundRectMaterialPlugin`.
+pub struct RoundRectInstancePlugin;
+
+impl Plugin for RoundRectInstancePlugin {
+    fn build(&self, app: &mut App) {
+        load_shared_shaders(app);
+        load_internal_asset!(
+            app,
+            ROUND_RECT_INSTANCED_SHADER_HANDLE,
+            "round_rect_instanced.wgsl",
+            Shader::from_wgsl
+        );
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_render_command::<TransparentUi, DrawRoundRectInstances>()
+            .init_resource::<ExtractedRoundRectInstances>()
+            .init_resource::<RoundRectInstanceMeta>()
+            .init_resource::<SpecializedRenderPipelines<RoundRectInstancePipeline>>()
+            .add_systems(
+                ExtractSchedule,
+                extract_round_rect_instances.in_set(RenderUiSystem::ExtractBackgrounds),
+            )
+            .add_systems(
+                Render,
+                (
+                    queue_round_rect_instances.in_set(RenderSet::Queue),
+                    prepare_round_rect_instances.in_set(RenderSet::PrepareBindGroups),
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.init_resource::<RoundRectInstancePipeline>();
+        }
+    }
+}
+
+/// A rounded rect drawn without a material, for UIs with many differently colored nodes.
+///
+/// Each node with a `RoundRectUiMaterial` needs a material asset and a bind group of its own, so every node with
+/// different colors is a separate draw call. Nodes with this component instead have their parameters packed into a
+/// single instance buffer, and consecutive nodes in the UI stack are drawn together in one draw call.
+///
+/// Only the background and border colors, border radius, offset and edge softness are supported, with the same meaning
+/// as on `RoundRectUiMaterial`. Use a `NodeBundle` for the node, rather than a `MaterialNodeBundle`.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn spawn_cell(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle {
+///             style: Style {
+///                 width: Val::Px(40.),
+///                 height: Val::Px(40.),
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         RoundRectUiInstance {
+///             background_color: LinearRgba::RED,
+///             border_radius: RoundUiBorder::all(8.),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct RoundRectUiInstance {
+    /// The background color of the node
+    pub background_color: LinearRgba,
+    /// The border color of the node
+    pub border_color: LinearRgba,
+    /// The border radius of each corner
+    pub border_radius: RoundUiBorder,
+    /// The border offset along each side of the rect
+    pub offset: RoundUiOffset,
+    /// Width of the anti-aliased edges of the shape, in pixels. See [edge softness](crate#edge-softness).
+    pub edge_softness: f32,
+}
+
+impl Default for RoundRectUiInstance {
+    fn default() -> Self {
+        Self {
+            background_color: LinearRgba::WHITE,
+            border_color: LinearRgba::NONE,
+            border_radius: RoundUiBorder::default(),
+            offset: RoundUiOffset::default(),
+            edge_softness: 0.,
+        }
+    }
+}
+
+use shader_types::RoundRectInstance;
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// The per-instance vertex data of a `RoundRectUiInstance`. Every field is a `vec4`, so the layout matches the vertex
+    /// attributes in `round_rect_instanced.wgsl`. Scalar parameters are packed into the unused lanes of `size`.
+    #[derive(Clone, Copy, Default, ShaderType)]
+    pub(super) struct RoundRectInstance {
+        pub(super) x_axis: Vec4,
+        pub(super) y_axis: Vec4,
+        pub(super) translation: Vec4,
+        pub(super) size: Vec4,
+        pub(super) clip: Vec4,
+        pub(super) background_color: LinearRgba,
+        pub(super) border_color: LinearRgba,
+        pub(super) border_radius: Vec4,
+        pub(super) offset: Vec4,
+    }
+}
+
+const ROUND_RECT_INSTANCE_ATTRIBUTES: usize = 9;
+
+struct ExtractedRoundRectInstance {
+    stack_index: usize,
+    camera_entity: Entity,
+    instance: RoundRectInstance,
+}
+
+#[derive(Resource, Default)]
+struct ExtractedRoundRectInstances {
+    instances: EntityHashMap<ExtractedRoundRectInstance>,
+}
+
+#[derive(Resource)]
+struct RoundRectInstanceMeta {
+    instances: BufferVec<RoundRectInstance>,
+    view_bind_group: Option<BindGroup>,
+}
+
+impl Default for RoundRectInstanceMeta {
+    fn default() -> Self {
+        Self {
+            instances: BufferVec::new(BufferUsages::VERTEX),
+            view_bind_group: None,
+        }
+    }
+}
+
+/// A range of instances drawn with a single draw call.
+#[derive(Component)]
+struct RoundRectInstanceBatch {
+    range: Range<u32>,
+}
+
+#[derive(Resource)]
+struct RoundRectInstancePipeline {
+    view_layout: BindGroupLayout,
+}
+
+impl FromWorld for RoundRectInstancePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let view_layout = render_device.create_bind_group_layout(
+            "round_rect_instance_view_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::VERTEX_FRAGMENT,
+                uniform_buffer::<ViewUniform>(true),
+            ),
+        );
+        Self { view_layout }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct RoundRectInstancePipelineKey {
+    hdr: bool,
+}
+
+impl SpecializedRenderPipeline for RoundRectInstancePipeline {
+    type Key = RoundRectInstancePipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let instance_layout = VertexBufferLayout::from_vertex_formats(
+            VertexStepMode::Instance,
+            vec![VertexFormat::Float32x4; ROUND_RECT_INSTANCE_ATTRIBUTES],
+        );
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: ROUND_RECT_INSTANCED_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: Vec::new(),
+                buffers: vec![instance_layout],
+            },
+            fragment: Some(FragmentState {
+                shader: ROUND_RECT_INSTANCED_SHADER_HANDLE,
+                shader_defs: Vec::new(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: if key.hdr {
+                        ViewTarget::TEXTURE_FORMAT_HDR
+                    } else {
+                        TextureFormat::bevy_default()
+                    },
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout: vec![self.view_layout.clone()],
+            push_constant_ranges: Vec::new(),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            label: Some("round_rect_instance_pipeline".into()),
+        }
+    }
+}
+
+type DrawRoundRectInstances = (
+    SetItemPipeline,
+    SetRoundRectInstanceViewBindGroup<0>,
+    DrawRoundRectInstanceBatch,
+);
+
+struct SetRoundRectInstanceViewBindGroup<const I: usize>;
+
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetRoundRectInstanceViewBindGroup<I> {
+    type Param = SRes<RoundRectInstanceMeta>;
+    type ViewQuery = Read<ViewUniformOffset>;
+    type ItemQuery = ();
+
+    fn render<'w>(
+        _item: &P,
+        view_uniform: &'w ViewUniformOffset,
+        _entity: Option<()>,
+        meta: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(view_bind_group) = meta.into_inner().view_bind_group.as_ref() else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(I, view_bind_group, &[view_uniform.offset]);
+        RenderCommandResult::Success
+    }
+}
+
+struct DrawRoundRectInstanceBatch;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawRoundRectInstanceBatch {
+    type Param = SRes<RoundRectInstanceMeta>;
+    type ViewQuery = ();
+    type ItemQuery = Read<RoundRectInstanceBatch>;
+
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        batch: Option<&'w RoundRectInstanceBatch>,
+        meta: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(batch) = batch else {
+            return RenderCommandResult::Failure;
+        };
+        let Some(instances) = meta.into_inner().instances.buffer() else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_vertex_buffer(0, instances.slice(..));
+        pass.draw(0..6, batch.range.clone());
+        RenderCommandResult::Success
+    }
+}
+
+/// Extracts the visible `RoundRectUiInstance` nodes, in the order of the UI stack.
+#[allow(clippy::type_complexity)]
+fn extract_round_rect_instances(
+    mut extracted: ResMut<ExtractedRoundRectInstances>,
+    ui_stack: Extract<Res<UiStack>>,
+    default_ui_camera: Extract<DefaultUiCamera>,
+    query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &RoundRectUiInstance,
+            &ViewVisibility,
+            Option<&CalculatedClip>,
+            Option<&TargetCamera>,
+        )>,
+    >,
+) {
+    let default_single_camera = default_ui_camera.get();
+
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        let Ok((node, transform, instance, view_visibility, clip, camera)) = query.get(*entity)
+        else {
+            continue;
+        };
+        let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_single_camera) else {
+            continue;
+        };
+        let size = node.size();
+        if !view_visibility.get() || size.x <= 0. || size.y <= 0. {
+            continue;
+        }
+
+        let transform = transform.compute_matrix();
+        let clip = clip.map_or(Rect::new(f32::MIN, f32::MIN, f32::MAX, f32::MAX), |clip| {
+            clip.clip
+        });
+        // Skip nodes that are clipped entirely, unless they are rotated
+        if transform.x_axis.y == 0. {
+            let center = transform.w_axis.truncate().truncate();
+            let half_size = 0.5 * size * Vec2::new(transform.x_axis.x, transform.y_axis.y).abs();
+            let rect = Rect::from_center_half_size(center, half_size);
+            if rect.intersect(clip).is_empty() {
+                continue;
+            }
+        }
+
+        extracted.instances.insert(
+            *entity,
+            ExtractedRoundRectInstance {
+                stack_index,
+                camera_entity,
+                instance: RoundRectInstance {
+                    x_axis: transform.x_axis,
+                    y_axis: transform.y_axis,
+                    translation: transform.w_axis,
+                    size: size.extend(instance.edge_softness).extend(0.),
+                    clip: Vec4::new(clip.min.x, clip.min.y, clip.max.x, clip.max.y),
+                    background_color: instance.background_color,
+                    border_color: instance.border_color,
+                    border_radius: instance.border_radius.into(),
+                    offset: instance.offset.into(),
+                },
+            },
+        );
+    }
+}
+
+/// Adds a phase item for each extracted node to the UI phase of its camera.
+fn queue_round_rect_instances(
+    extracted: Res<ExtractedRoundRectInstances>,
+    draw_functions: Res<DrawFunctions<TransparentUi>>,
+    pipeline: Res<RoundRectInstancePipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<RoundRectInstancePipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    mut phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
+    views: Query<&ExtractedView>,
+) {
+    let draw_function = draw_functions.read().id::<DrawRoundRectInstances>();
+
+    for (entity, extracted) in extracted.instances.iter() {
+        let Ok(view) = views.get(extracted.camera_entity) else {
+            continue;
+        };
+        let Some(phase) = phases.get_mut(&extracted.camera_entity) else {
+            continue;
+        };
+        let pipeline = pipelines.specialize(
+            &pipeline_cache,
+            &pipeline,
+            RoundRectInstancePipelineKey { hdr: view.hdr },
+        );
+        phase.add(TransparentUi {
+            draw_function,
+            pipeline,
+            entity: *entity,
+            sort_key: (FloatOrd(extracted.stack_index as f32), entity.index()),
+            batch_range: 0..0,
+            extra_index: PhaseItemExtraIndex::NONE,
+        });
+    }
+}
+
+/// Writes the instances to the instance buffer in the sorted order of the UI phases, and merges consecutive
+/// instances into batches.
+#[allow(clippy::too_many_arguments)]
+fn prepare_round_rect_instances(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut meta: ResMut<RoundRectInstanceMeta>,
+    mut extracted: ResMut<ExtractedRoundRectInstances>,
+    view_uniforms: Res<ViewUniforms>,
+    pipeline: Res<RoundRectInstancePipeline>,
+    draw_functions: Res<DrawFunctions<TransparentUi>>,
+    mut phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
+) {
+    let Some(view_binding) = view_uniforms.uniforms.binding() else {
+        extracted.instances.clear();
+        return;
+    };
+    meta.view_bind_group = Some(render_device.create_bind_group(
+        "round_rect_instance_view_bind_group",
+        &pipeline.view_layout,
+        &BindGroupEntries::single(view_binding),
+    ));
+
+    let draw_function = draw_functions.read().id::<DrawRoundRectInstances>();
+    let mut batches: Vec<(Entity, RoundRectInstanceBatch)> = Vec::new();
+    meta.instances.clear();
+
+    for phase in phases.values_mut() {
+        // The index of the phase item that draws the current batch
+        let mut batch_item_index: Option<usize> = None;
+
+        for item_index in 0..phase.items.len() {
+            let item = &phase.items[item_index];
+            let entity = item.entity;
+            let instance = extracted
+                .instances
+                .get(&entity)
+                .filter(|_| item.draw_function == draw_function);
+            let Some(instance) = instance else {
+                batch_item_index = None;
+                continue;
+            };
+
+            let index = meta.instances.push(instance.instance) as u32;
+            match batch_item_index {
+                Some(batch_item_index) => {
+                    if let Some((_, batch)) = batches.last_mut() {
+                        batch.range.end = index + 1;
+                    }
+                    phase.items[batch_item_index].batch_range_mut().end += 1;
+                }
+                None => {
+                    batch_item_index = Some(item_index);
+                    batches.push((
+                        entity,
+                        RoundRectInstanceBatch {
+                            range: index..index + 1,
+                        },
+                    ));
+                    phase.items[item_index].batch_range_mut().end += 1;
+                }
+            }
+        }
+    }
+
+    meta.instances.write_buffer(&render_device, &render_queue);
+    commands.insert_or_spawn_batch(batches);
+    extracted.instances.clear();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn instance_layout_matches_vertex_attributes() {
+        let attributes_size =
+            VertexFormat::Float32x4.size() * ROUND_RECT_INSTANCE_ATTRIBUTES as u64;
+        assert_eq!(RoundRectInstance::min_size().get(), attributes_size);
+    }
+}
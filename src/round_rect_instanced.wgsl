@@ -0,0 +1,98 @@
+#import bevy_round_ui::shared::{
+    coverage,
+    edge_width,
+    premultiply,
+    sdf_rounded_rect,
+    unpremultiply,
+    view,
+}
+
+/// Per-node parameters, matching `RoundRectInstance`.
+struct RoundRectInstance {
+    /// columns of the node's transform
+    @location(0) x_axis: vec4<f32>,
+    @location(1) y_axis: vec4<f32>,
+    @location(2) translation: vec4<f32>,
+    /// size of the node and width of the anti-aliased edge, in pixels:
+    /// (width, height, edge_softness, _)
+    @location(3) size: vec4<f32>,
+    /// clipping rect in world space: (min.x, min.y, max.x, max.y)
+    @location(4) clip: vec4<f32>,
+    @location(5) background_color: vec4<f32>,
+    @location(6) border_color: vec4<f32>,
+    /// border-radius of each corner:
+    // (bottom-right, top-right, bottom-left, top-left)
+    @location(7) border_radius: vec4<f32>,
+    /// border offset: (top, left, bottom, right)
+    @location(8) offset: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) world_position: vec2<f32>,
+    @location(2) @interpolate(flat) size: vec2<f32>,
+    @location(3) @interpolate(flat) clip: vec4<f32>,
+    @location(4) @interpolate(flat) background_color: vec4<f32>,
+    @location(5) @interpolate(flat) border_color: vec4<f32>,
+    @location(6) @interpolate(flat) border_radius: vec4<f32>,
+    @location(7) @interpolate(flat) offset: vec4<f32>,
+    @location(8) @interpolate(flat) edge_softness: f32,
+}
+
+@vertex
+fn vertex(@builtin(vertex_index) vertex_index: u32, instance: RoundRectInstance) -> VertexOutput {
+    // two triangles covering the node, centered on its transform
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-0.5, -0.5),
+        vec2<f32>(0.5, 0.5),
+        vec2<f32>(-0.5, 0.5),
+        vec2<f32>(-0.5, -0.5),
+        vec2<f32>(0.5, -0.5),
+        vec2<f32>(0.5, 0.5),
+    );
+    let corner = corners[vertex_index % 6u];
+    let local = corner * instance.size.xy;
+    let world = instance.x_axis.xyz * local.x + instance.y_axis.xyz * local.y + instance.translation.xyz;
+
+    var out: VertexOutput;
+    out.position = view.clip_from_world * vec4<f32>(world, 1.);
+    out.uv = corner + 0.5;
+    out.world_position = world.xy;
+    out.size = instance.size.xy;
+    out.clip = instance.clip;
+    out.background_color = instance.background_color;
+    out.border_color = instance.border_color;
+    out.border_radius = instance.border_radius;
+    out.offset = instance.offset;
+    out.edge_softness = instance.size.z;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if any(in.world_position < in.clip.xy) || any(in.world_position >= in.clip.zw) {
+        discard;
+    }
+
+    // The same shape as `RoundRectUiMaterial`, in the doubled pixel space
+    // centered on the node.
+    let uv = in.uv * in.size * 2.0 - in.size;
+    let border_offset = vec2<f32>(
+        in.offset.w - in.offset.y, // right - left
+        in.offset.z - in.offset.x, // bottom - top
+    );
+    let inner_size = in.size - vec2<f32>(
+        in.offset.y + in.offset.w, // left + right
+        in.offset.x + in.offset.z, // top + bottom
+    );
+    let d_inner = sdf_rounded_rect(uv + border_offset, inner_size, in.border_radius) * 0.5;
+    let d_outer = min(d_inner, sdf_rounded_rect(uv, in.size, in.border_radius) * 0.5);
+
+    let aa = edge_width(d_outer, in.edge_softness);
+    let alpha = coverage(d_outer, aa);
+    let inner = coverage(d_inner, aa);
+
+    let color = mix(premultiply(in.border_color), premultiply(in.background_color), inner) * alpha;
+    return unpremultiply(color);
+}
@@ -0,0 +1,245 @@
+use bevy::{
+    asset::load_internal_asset, prelude::*, render::render_resource::ShaderType, ui::UiSystem,
+};
+
+use crate::types::*;
+
+pub const SHARED_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(29746319837281640517);
+
+/// Loads the shader module shared by all materials, imported as `bevy_round_ui::shared`.
+///
+/// Called by each material plugin, so it is available no matter which features are enabled.
+pub(crate) fn load_shared_shaders(app: &mut App) {
+    load_internal_asset!(app, SHARED_SHADER_HANDLE, "shared.wgsl", Shader::from_wgsl);
+}
+
+/// Orders the `RoundUiSystem` sets relative to the UI layout.
+///
+/// Called by each material plugin, like `load_shared_shaders`.
+pub(crate) fn configure_system_sets(app: &mut App) {
+    app.configure_sets(
+        PostUpdate,
+        (
+            (
+                RoundUiSystem::Style,
+                RoundUiSystem::Interaction,
+                RoundUiSystem::Transition,
+                RoundUiSystem::Cooldown,
+                RoundUiSystem::Padding,
+            )
+                .chain()
+                .before(UiSystem::Layout),
+            RoundUiSystem::Responsive.after(UiSystem::Layout),
+        ),
+    );
+}
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+pub(crate) use shader_types::{
+    RoundUiBorderStyleUniform, RoundUiGradientUniform, RoundUiImageUniform, RoundUiSweepMaskUniform,
+};
+
+// The `ShaderType` derive generates per-field assertion functions that are never called, next to the struct rather
+// than inside it, so `dead_code` can only be allowed for a whole module. The GPU representations therefore live in a
+// module of their own.
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// GPU representation of an optional `RoundUiGradient`. A `count` of zero means the solid color is used instead.
+    #[derive(Clone, Copy, Debug, Default, ShaderType)]
+    pub(crate) struct RoundUiGradientUniform {
+        pub(super) colors: [Vec4; RoundUiGradient::MAX_STOPS],
+        /// Stop positions, packed four to a vector
+        pub(super) positions: [Vec4; RoundUiGradient::MAX_STOPS / 4],
+        /// Parameters of the fill mode
+        pub(super) params: Vec4,
+        pub(super) mode: u32,
+        pub(super) count: u32,
+        pub(super) color_space: u32,
+    }
+
+    /// GPU representation of a `RoundUiBorderStyle`.
+    #[derive(Clone, Copy, Debug, Default, ShaderType)]
+    pub(crate) struct RoundUiBorderStyleUniform {
+        pub(super) length: f32,
+        pub(super) gap: f32,
+        pub(super) phase: f32,
+        pub(super) style: u32,
+    }
+
+    /// GPU representation of a `RoundUiSweepMask`.
+    #[derive(Clone, Copy, Debug, Default, ShaderType)]
+    pub(crate) struct RoundUiSweepMaskUniform {
+        pub(super) color: LinearRgba,
+        pub(super) fraction: f32,
+        pub(super) start_angle: f32,
+        pub(super) direction: u32,
+    }
+
+    /// GPU representation of the image fill of a material. A `fit` of zero means no image is drawn.
+    #[derive(Clone, Copy, Debug, Default, ShaderType)]
+    pub(crate) struct RoundUiImageUniform {
+        pub(super) tint: LinearRgba,
+        pub(super) fit: u32,
+    }
+}
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+impl From<Option<&RoundUiGradient>> for RoundUiGradientUniform {
+    fn from(gradient: Option<&RoundUiGradient>) -> Self {
+        let Some(gradient) = gradient else {
+            return Self::default();
+        };
+
+        let (mode, params) = match gradient.mode {
+            RoundUiFillMode::Linear { angle } => (0, Vec4::new(angle, 0., 0., 0.)),
+            RoundUiFillMode::Radial { center, radius } => {
+                (1, center.extend(radius.x).extend(radius.y))
+            }
+            RoundUiFillMode::Conic {
+                center,
+                start_angle,
+            } => (2, center.extend(start_angle).extend(0.)),
+        };
+        let mut uniform = Self {
+            params,
+            mode,
+            color_space: match gradient.color_space {
+                RoundUiColorSpace::LinearRgb => 0,
+                RoundUiColorSpace::Oklab => 1,
+            },
+            ..default()
+        };
+        for (i, stop) in gradient
+            .stops
+            .iter()
+            .take(RoundUiGradient::MAX_STOPS)
+            .enumerate()
+        {
+            let color = stop.color;
+            uniform.colors[i] = Vec4::new(color.red, color.green, color.blue, color.alpha);
+            uniform.positions[i / 4][i % 4] = stop.position;
+            uniform.count += 1;
+        }
+        uniform
+    }
+}
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+impl From<RoundUiBorderStyle> for RoundUiBorderStyleUniform {
+    fn from(style: RoundUiBorderStyle) -> Self {
+        match style {
+            RoundUiBorderStyle::Solid => Self::default(),
+            RoundUiBorderStyle::Dashed { length, gap, phase } => Self {
+                length,
+                gap,
+                phase,
+                style: 1,
+            },
+            RoundUiBorderStyle::Dotted { gap, phase } => Self {
+                length: 0.,
+                gap,
+                phase,
+                style: 2,
+            },
+        }
+    }
+}
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+impl From<RoundUiSweepMask> for RoundUiSweepMaskUniform {
+    fn from(mask: RoundUiSweepMask) -> Self {
+        Self {
+            color: mask.color,
+            fraction: mask.fraction.clamp(0., 1.),
+            start_angle: mask.start_angle,
+            direction: mask.direction.into(),
+        }
+    }
+}
+
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+impl RoundUiImageUniform {
+    pub(crate) fn new(
+        image: Option<&Handle<Image>>,
+        fit: RoundUiImageFit,
+        tint: LinearRgba,
+    ) -> Self {
+        if image.is_none() {
+            return Self::default();
+        }
+        Self {
+            tint,
+            fit: match fit {
+                RoundUiImageFit::Cover => 1,
+                RoundUiImageFit::Contain => 2,
+                RoundUiImageFit::Stretch => 3,
+                RoundUiImageFit::Tile => 4,
+            },
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(feature = "round_rect", feature = "superellipse", feature = "polygon")
+))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gradient_uniform_packs_stops() {
+        let gradient = RoundUiGradient::linear(0., [LinearRgba::WHITE; 10]);
+        let uniform = RoundUiGradientUniform::from(Some(&gradient));
+        assert_eq!(uniform.count, RoundUiGradient::MAX_STOPS as u32);
+        assert_eq!(uniform.positions[0].x, 0.);
+        assert_eq!(uniform.positions[1].x, gradient.stops[4].position);
+        assert_eq!(uniform.positions[1].w, gradient.stops[7].position);
+    }
+
+    #[test]
+    fn gradient_uniform_none_is_empty() {
+        let uniform = RoundUiGradientUniform::from(None);
+        assert_eq!(uniform.count, 0);
+    }
+
+    #[test]
+    fn border_style_uniform_modes() {
+        assert_eq!(
+            RoundUiBorderStyleUniform::from(RoundUiBorderStyle::Solid).style,
+            0
+        );
+        let dashed =
+            RoundUiBorderStyleUniform::from(RoundUiBorderStyle::dashed(8., 4.).with_phase(2.));
+        assert_eq!(
+            (dashed.style, dashed.length, dashed.gap, dashed.phase),
+            (1, 8., 4., 2.)
+        );
+        let dotted = RoundUiBorderStyleUniform::from(RoundUiBorderStyle::dotted(4.));
+        assert_eq!((dotted.style, dotted.gap), (2, 4.));
+    }
+
+    #[test]
+    fn sweep_mask_uniform_clamps_fraction() {
+        let mask = RoundUiSweepMask::new(LinearRgba::BLACK, 1.5)
+            .with_direction(RoundUiSweepDirection::CounterClockwise);
+        let uniform = RoundUiSweepMaskUniform::from(mask);
+        assert_eq!((uniform.fraction, uniform.direction), (1., 1));
+        assert_eq!(
+            RoundUiSweepMaskUniform::from(RoundUiSweepMask::default()).fraction,
+            0.
+        );
+    }
+
+    #[test]
+    fn image_uniform_without_image_is_disabled() {
+        let uniform = RoundUiImageUniform::new(None, RoundUiImageFit::Tile, LinearRgba::WHITE);
+        assert_eq!(uniform.fit, 0);
+
+        let image = Handle::<Image>::default();
+        let uniform =
+            RoundUiImageUniform::new(Some(&image), RoundUiImageFit::Cover, LinearRgba::WHITE);
+        assert_eq!(uniform.fit, 1);
+    }
+}
@@ -0,0 +1,740 @@
+#define_import_path bevy_round_ui::shared
+
+#import bevy_render::{maths::{HALF_PI, PI, PI_2}, view::View}
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(0) @binding(0)
+var<uniform> view: View;
+
+/// A drop or inset shadow, matching `RoundUiShadow`.
+struct RoundUiShadow {
+    color: vec4<f32>,
+    /// offset of the shadow in pixels: (x, y)
+    offset: vec2<f32>,
+    /// blur radius in pixels
+    blur: f32,
+    /// distance in pixels to grow (or shrink) the shadow shape by
+    spread: f32,
+}
+
+/// An outline drawn outside the shape, matching `RoundUiOutline`.
+struct RoundUiOutline {
+    color: vec4<f32>,
+    /// width of the outline in pixels
+    width: f32,
+    /// gap in pixels between the shape and the outline
+    offset: f32,
+}
+
+/// A "clock wipe" overlay, matching `RoundUiSweepMaskUniform`.
+struct RoundUiSweepMask {
+    color: vec4<f32>,
+    /// part of a full turn covered by the overlay, from 0 to 1
+    fraction: f32,
+    /// angle the sweep starts from, in radians, where 0 points up and
+    /// positive angles rotate clockwise
+    start_angle: f32,
+    /// 0: clockwise, 1: counterclockwise
+    direction: u32,
+}
+
+/// Maximum number of color stops in a gradient, matching
+/// `RoundUiGradient::MAX_STOPS`.
+const MAX_GRADIENT_STOPS: u32 = 8u;
+
+/// An optional gradient, matching `RoundUiGradientUniform`. A `count` of zero
+/// means no gradient is used.
+struct RoundUiGradient {
+    colors: array<vec4<f32>, 8>,
+    /// stop positions, packed four to a vector
+    positions: array<vec4<f32>, 2>,
+    /// parameters of the fill mode:
+    /// - linear: (angle, _, _, _)
+    /// - radial: (center.x, center.y, radius.x, radius.y)
+    /// - conic: (center.x, center.y, start_angle, _)
+    /// where positions are relative to the node size, and angles are in
+    /// radians, with 0 pointing up and rotating clockwise.
+    params: vec4<f32>,
+    /// 0: linear, 1: radial, 2: conic
+    mode: u32,
+    count: u32,
+    /// 0: linear RGB, 1: Oklab
+    color_space: u32,
+}
+
+/// An optional image fill, matching `RoundUiImageUniform`.
+struct RoundUiImage {
+    tint: vec4<f32>,
+    /// 0: no image, 1: cover, 2: contain, 3: stretch, 4: tile
+    fit: u32,
+}
+
+/// The style of a border, matching `RoundUiBorderStyleUniform`.
+struct RoundUiBorderStyle {
+    /// length of each dash in pixels
+    length: f32,
+    /// gap between dashes or dots in pixels
+    gap: f32,
+    /// distance in pixels to shift the pattern along the border
+    phase: f32,
+    /// 0: solid, 1: dashed, 2: dotted
+    style: u32,
+}
+
+/// Builds the vertex output for a node whose quad is grown outwards by
+/// `extents` pixels: (left, top, right, bottom).
+///
+/// The UVs are extended past `0.0..1.0` by the same amount, so the fragment
+/// shader can keep computing positions relative to the node. Vertices that
+/// were moved inwards by clipping are left untouched, so clipped sides stay
+/// clipped.
+fn expand_vertex(
+    position: vec3<f32>,
+    uv: vec2<f32>,
+    size: vec2<f32>,
+    border_widths: vec4<f32>,
+    extents: vec4<f32>,
+) -> UiVertexOutput {
+    let grow = vec2<f32>(
+        select(0., -extents.x, uv.x <= 0.) + select(0., extents.z, uv.x >= 1.),
+        select(0., -extents.y, uv.y <= 0.) + select(0., extents.w, uv.y >= 1.),
+    );
+
+    var out: UiVertexOutput;
+    out.uv = uv + grow / size;
+    out.position = view.clip_from_world * vec4<f32>(position.xy + grow, position.z, 1.);
+    out.size = size;
+    out.border_widths = border_widths;
+    return out;
+}
+
+/// How far, in pixels, a shadow reaches past each side of the node:
+/// (left, top, right, bottom).
+fn shadow_extents(shadow: RoundUiShadow) -> vec4<f32> {
+    if shadow.color.a <= 0. {
+        return vec4<f32>(0.);
+    }
+    // one extra pixel leaves room for the anti-aliased edge
+    let reach = shadow.spread + max(shadow.blur, 0.) + 1.;
+    return max(
+        vec4<f32>(reach) + vec4<f32>(-shadow.offset, shadow.offset),
+        vec4<f32>(0.),
+    );
+}
+
+/// The part of a rect with half-size `b` covered by a progress fill of
+/// `fraction`, as (center.x, center.y, half_size.x, half_size.y) relative to
+/// the center of the rect. `direction` matches `RoundUiFillDirection`:
+/// 0: left to right, 1: right to left, 2: top to bottom, 3: bottom to top
+fn progress_rect(b: vec2<f32>, fraction: f32, direction: u32) -> vec4<f32> {
+    let f = clamp(fraction, 0., 1.);
+    if direction < 2u {
+        let half_width = b.x * f;
+        let start = select(-b.x + half_width, b.x - half_width, direction == 1u);
+        return vec4<f32>(start, 0., half_width, b.y);
+    }
+    let half_height = b.y * f;
+    let start = select(-b.y + half_height, b.y - half_height, direction == 3u);
+    return vec4<f32>(0., start, b.x, half_height);
+}
+
+/// How far, in pixels, an outline reaches past each side of the node:
+/// (left, top, right, bottom).
+fn outline_extents(outline: RoundUiOutline) -> vec4<f32> {
+    if outline.color.a <= 0. || outline.width <= 0. {
+        return vec4<f32>(0.);
+    }
+    // one extra pixel leaves room for the anti-aliased edge
+    return vec4<f32>(max(outline.offset + outline.width, 0.) + 1.);
+}
+
+/// The premultiplied color of an outline at signed distance `d` from the
+/// outer edge of the shape. Growing the distance by the offset also grows the
+/// corner radii.
+fn outline_color(outline: RoundUiOutline, d: f32, aa: f32) -> vec4<f32> {
+    if outline.color.a <= 0. || outline.width <= 0. {
+        return vec4<f32>(0.);
+    }
+    let half_width = 0.5 * outline.width;
+    let d_outline = abs(d - outline.offset - half_width) - half_width;
+    return premultiply(outline.color) * coverage(d_outline, aa);
+}
+
+/// The premultiplied color of a sweep mask at `p`, in pixels relative to the
+/// center of the sweep.
+///
+/// The overlay covers the part of the turn the sweep hasn't reached yet: it
+/// ends at the start angle, so its leading edge moves around in the sweep
+/// direction as the fraction goes down.
+fn sweep_mask_color(mask: RoundUiSweepMask, p: vec2<f32>, aa: f32) -> vec4<f32> {
+    if mask.color.a <= 0. || mask.fraction <= 0. {
+        return vec4<f32>(0.);
+    }
+    if mask.fraction >= 1. {
+        return premultiply(mask.color);
+    }
+
+    // Rotate so the middle of the covered sector points along the positive y
+    // axis, flipping y so it points up. The sector reaches `aperture` radians
+    // to each side of it.
+    let aperture = PI * mask.fraction;
+    let sweep_sign = select(1., -1., mask.direction == 1u);
+    let middle = mask.start_angle - sweep_sign * aperture;
+    let up = vec2<f32>(p.x, -p.y);
+    let q = vec2<f32>(
+        abs(up.x * cos(middle) - up.y * sin(middle)),
+        up.x * sin(middle) + up.y * cos(middle),
+    );
+
+    // Signed distance to the edges of the sector, negative inside it
+    let edge = vec2<f32>(sin(aperture), cos(aperture));
+    let side = edge.x * q.y - edge.y * q.x;
+    let d_edge = select(length(q), abs(side), dot(q, edge) > 0.);
+    let d = select(d_edge, -d_edge, side >= 0.);
+    return premultiply(mask.color) * coverage(d, aa);
+}
+
+// MIT License. © 2023 Inigo Quilez, Munrocket
+// https://gist.github.com/munrocket/30e645d584b5300ee69295e54674b3e4
+// https://compute.toys/view/398
+fn sdf_rounded_rect(p: vec2f, b: vec2f, r: vec4f) -> f32 {
+    var x = r.x;
+    var y = r.y;
+    x = select(r.z, r.x, p.x > 0.);
+    y = select(r.w, r.y, p.x > 0.);
+    x = select(y, x, p.y > 0.);
+    let q = abs(p) - b + x;
+    return min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.))) - x;
+}
+
+/// Point on the cubic bezier curve with control points `a`, `b`, `c`, `d`.
+fn cubic_point(a: vec2f, b: vec2f, c: vec2f, d: vec2f, t: f32) -> vec2f {
+    let u = 1. - t;
+    return u * u * u * a + 3. * u * u * t * b + 3. * u * t * t * c + t * t * t * d;
+}
+
+/// Tangent of the cubic bezier curve with control points `a`, `b`, `c`, `d`.
+fn cubic_tangent(a: vec2f, b: vec2f, c: vec2f, d: vec2f, t: f32) -> vec2f {
+    let u = 1. - t;
+    return 3. * u * u * (b - a) + 6. * u * t * (c - b) + 3. * t * t * (d - c);
+}
+
+/// Number of bisection steps used when searching for the closest point on the
+/// curves of a smoothed corner.
+const SMOOTH_CORNER_ITERATIONS: i32 = 12;
+
+/// Signed distance from `q` to a corner of radius `r` with Figma-style corner
+/// smoothing, where `q` is relative to the corner of the rect and negative
+/// inside it. `budget` is the longest the corner may reach along each side.
+///
+/// The corner is a circular arc of radius `r` joined to the sides by two cubic
+/// bezier curves, which reach `(1 + smoothing) * r` along each side. As in
+/// Figma, the smoothing is reduced when the corner doesn't fit.
+fn sd_smooth_corner(q_in: vec2f, r_in: f32, smoothing_in: f32, budget: f32) -> f32 {
+    let r = min(r_in, budget);
+    let smoothing = clamp(min(smoothing_in, budget / r - 1.), 0., 1.);
+    let extent = (1. + smoothing) * r;
+
+    // the corner is symmetric, so only the half joining the top side is used
+    let q = select(q_in, q_in.yx, q_in.y < q_in.x);
+
+    // lengths of the parts of the curve, as in the figma-squircle library
+    let arc_measure = HALF_PI * (1. - smoothing);
+    let arc_section = sin(0.5 * arc_measure) * r * sqrt(2.);
+    let angle_alpha = 0.5 * (HALF_PI - arc_measure);
+    let angle_beta = 0.25 * PI * smoothing;
+    let c = r * tan(0.5 * angle_alpha) * cos(angle_beta);
+    let d = c * tan(angle_beta);
+    let b = (extent - arc_section - c - d) / 3.;
+    let a = 2. * b;
+
+    // the bezier curve from the top side to the start of the arc
+    let p0 = vec2f(-extent, 0.);
+    let p1 = vec2f(a - extent, 0.);
+    let p2 = vec2f(a + b - extent, 0.);
+    let p3 = vec2f(a + b + c - extent, -d);
+    let center = vec2f(-r);
+
+    // bisect for the point where `q - curve` is perpendicular to the curve
+    var lo = 0.;
+    var hi = 1.;
+    for (var i = 0; i < SMOOTH_CORNER_ITERATIONS; i++) {
+        let t = 0.5 * (lo + hi);
+        let delta = q - cubic_point(p0, p1, p2, p3, t);
+        if dot(delta, cubic_tangent(p0, p1, p2, p3, t)) > 0. {
+            lo = t;
+        } else {
+            hi = t;
+        }
+    }
+    let t = 0.5 * (lo + hi);
+    let closest = cubic_point(p0, p1, p2, p3, t);
+    let tangent = cubic_tangent(p0, p1, p2, p3, t);
+    // outward normal, falling back to the direction from the arc center when
+    // the curve degenerates to a point
+    var normal = vec2f(-tangent.y, tangent.x);
+    if dot(tangent, tangent) < 1e-10 {
+        normal = closest - center;
+    }
+    var d_corner = distance(q, closest) * select(-1., 1., dot(q - closest, normal) >= 0.);
+
+    // the arc, between the end of the bezier curve and the diagonal
+    let v = q - center;
+    let to_p3 = p3 - center;
+    if v.y >= v.x && v.x * to_p3.y - v.y * to_p3.x >= 0. {
+        let d_arc = length(v) - r;
+        if abs(d_arc) < abs(d_corner) {
+            d_corner = d_arc;
+        }
+    }
+    return d_corner;
+}
+
+/// Signed distance to a rect with half-size `b` and Figma-style smoothed
+/// corners of radius `r`: (bottom-right, top-right, bottom-left, top-left).
+fn sdf_smooth_rect(p: vec2f, b: vec2f, r: vec4f, smoothing: f32) -> f32 {
+    let r_xy = select(r.zw, r.xy, p.x > 0.);
+    let radius = select(r_xy.y, r_xy.x, p.y > 0.);
+    let budget = min(b.x, b.y);
+    if smoothing <= 0. || radius <= 0. || budget <= 0. {
+        return sdf_rounded_rect(p, b, r);
+    }
+
+    let q = abs(p) - b;
+    let d_rect = min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.)));
+    let extent = min((1. + smoothing) * min(radius, budget), budget);
+    if q.x < -extent || q.y < -extent {
+        return d_rect;
+    }
+    return max(d_rect, sd_smooth_corner(q, radius, smoothing, budget));
+}
+
+/// Like `sdf_smooth_rect`, with the shape of each corner given by `corners`
+/// in the same order as `r`, matching `RoundUiCornerType`:
+/// 0: round, 1: chamfer, 2: notch, 3: scoop
+///
+/// The smoothing only applies to round corners.
+fn sdf_cornered_rect(p: vec2f, b: vec2f, r: vec4f, corners: vec4<u32>, smoothing: f32) -> f32 {
+    // the radius and type of the corner in the quadrant of `p`
+    let right = p.x > 0.;
+    let bottom = p.y > 0.;
+    let radius = select(select(r.w, r.y, right), select(r.z, r.x, right), bottom);
+    let corner = select(select(corners.w, corners.y, right), select(corners.z, corners.x, right), bottom);
+    if corner == 0u || radius <= 0. {
+        return sdf_smooth_rect(p, b, r, smoothing);
+    }
+
+    // position relative to the corner, negative inside the rect
+    let q = abs(p) - b;
+    let d_rect = min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.)));
+    switch corner {
+        case 1u: {
+            // cut by the line through the points `radius` away from the
+            // corner along each side
+            return max(d_rect, (q.x + q.y + radius) * 0.70710678);
+        }
+        case 2u: {
+            // cut by a square of `radius` around the corner
+            let s = abs(q) - radius;
+            let d_square = min(max(s.x, s.y), 0.) + length(max(s, vec2f(0.)));
+            return max(d_rect, -d_square);
+        }
+        default: {
+            // cut by a circle of `radius` around the corner
+            return max(d_rect, radius - length(q));
+        }
+    }
+}
+
+/// Approximation of the error function, with a maximum error of 5e-4.
+fn erf(x: f32) -> f32 {
+    let a = abs(x);
+    let t = 1. + (0.278393 + (0.230389 + (0.000972 + 0.078108 * a) * a) * a) * a;
+    let y = 1. - 1. / (t * t * t * t);
+    return sign(x) * y;
+}
+
+/// Width of the anti-aliased transition along the edges of a shape with
+/// signed distance `d`: roughly one pixel, or `edge_softness` pixels if the
+/// material asks for softer edges.
+fn edge_width(d: f32, edge_softness: f32) -> f32 {
+    return max(max(fwidth(d), edge_softness), 0.0001);
+}
+
+/// Coverage of a shape with signed distance `d`, anti-aliased over a
+/// transition `aa` pixels wide.
+fn coverage(d: f32, aa: f32) -> f32 {
+    return 1. - smoothstep(-0.5 * aa, 0.5 * aa, d);
+}
+
+/// Coverage of a shadow with signed distance `d` to its (spread) shape.
+///
+/// The shape is blurred with a gaussian whose standard deviation is half the
+/// blur radius, as with CSS box shadows. This is exact along straight edges
+/// and a close approximation around the corners.
+fn shadow_coverage(d: f32, blur: f32, aa: f32) -> f32 {
+    if blur <= 0. {
+        return coverage(d, aa);
+    }
+    let sigma = 0.5 * blur;
+    return 0.5 * (1. - erf(d / (sigma * sqrt(2.))));
+}
+
+/// Coverage of an inset shadow, where `d` is the signed distance to the hole
+/// cut out of the shadow: the inner shape moved by the shadow offset and
+/// shrunk by the spread.
+fn inset_shadow_coverage(d: f32, blur: f32, aa: f32) -> f32 {
+    return 1. - shadow_coverage(d, blur, aa);
+}
+
+/// Composites the premultiplied color `top` over the premultiplied color
+/// `bottom`.
+fn blend_over(top: vec4<f32>, bottom: vec4<f32>) -> vec4<f32> {
+    return top + bottom * (1. - top.a);
+}
+
+fn linear_rgb_to_oklab(c: vec3<f32>) -> vec3<f32> {
+    let lms = vec3<f32>(
+        0.4122214708 * c.r + 0.5363325363 * c.g + 0.0514459929 * c.b,
+        0.2119034982 * c.r + 0.6806995451 * c.g + 0.1073969566 * c.b,
+        0.0883024619 * c.r + 0.2817188376 * c.g + 0.6299787005 * c.b,
+    );
+    let l = sign(lms) * pow(abs(lms), vec3<f32>(1. / 3.));
+    return vec3<f32>(
+        0.2104542553 * l.x + 0.7936177850 * l.y - 0.0040720468 * l.z,
+        1.9779984951 * l.x - 2.4285922050 * l.y + 0.4505937099 * l.z,
+        0.0259040371 * l.x + 0.7827717662 * l.y - 0.8086757660 * l.z,
+    );
+}
+
+fn oklab_to_linear_rgb(c: vec3<f32>) -> vec3<f32> {
+    let l = vec3<f32>(
+        c.x + 0.3963377774 * c.y + 0.2158037573 * c.z,
+        c.x - 0.1055613458 * c.y - 0.0638541728 * c.z,
+        c.x - 0.0894841775 * c.y - 1.2914855480 * c.z,
+    );
+    let lms = l * l * l;
+    return vec3<f32>(
+        4.0767416621 * lms.x - 3.3077115913 * lms.y + 0.2309699292 * lms.z,
+        -1.2684380046 * lms.x + 2.6097574011 * lms.y - 0.3413193965 * lms.z,
+        -0.0041960863 * lms.x - 0.5131005580 * lms.y + 1.2301095070 * lms.z,
+    );
+}
+
+/// Interpolates between two (straight alpha) colors in the gradient's color
+/// space, returning a premultiplied color.
+fn gradient_mix(gradient: RoundUiGradient, a: vec4<f32>, b: vec4<f32>, t: f32) -> vec4<f32> {
+    if gradient.color_space == 1u {
+        // interpolate premultiplied Oklab coordinates
+        let lab = mix(
+            linear_rgb_to_oklab(a.rgb) * a.a,
+            linear_rgb_to_oklab(b.rgb) * b.a,
+            t,
+        );
+        let alpha = mix(a.a, b.a, t);
+        if alpha <= 0. {
+            return vec4<f32>(0.);
+        }
+        return vec4<f32>(max(oklab_to_linear_rgb(lab / alpha), vec3<f32>(0.)) * alpha, alpha);
+    }
+    return mix(premultiply(a), premultiply(b), t);
+}
+
+/// Position along a gradient, from 0 at the start to 1 at the end, for the
+/// pixel `p` relative to the center of a node of the given size.
+fn gradient_position(gradient: RoundUiGradient, p: vec2<f32>, size: vec2<f32>) -> f32 {
+    switch gradient.mode {
+        // radial
+        case 1u: {
+            let center = (gradient.params.xy - 0.5) * size;
+            let radius = max(gradient.params.zw * size, vec2<f32>(0.0001));
+            return length((p - center) / radius);
+        }
+        // conic
+        case 2u: {
+            let center = (gradient.params.xy - 0.5) * size;
+            let d = p - center;
+            // angle from the top, increasing clockwise (y points down)
+            let angle = atan2(d.x, -d.y) - gradient.params.z;
+            return fract(angle / PI_2);
+        }
+        // linear
+        default: {
+            // As in CSS, the gradient line passes through the center of the
+            // node, and is long enough for the corners to reach both ends.
+            let angle = gradient.params.x;
+            let dir = vec2<f32>(sin(angle), -cos(angle));
+            let length = abs(size.x * dir.x) + abs(size.y * dir.y);
+            return dot(p, dir) / max(length, 0.0001) + 0.5;
+        }
+    }
+}
+
+/// Samples a gradient at position `t`, returning a premultiplied color.
+fn sample_gradient(gradient: RoundUiGradient, t: f32) -> vec4<f32> {
+    // arrays can only be indexed dynamically through a variable
+    var colors = gradient.colors;
+    var positions = gradient.positions;
+
+    let count = min(gradient.count, MAX_GRADIENT_STOPS);
+    if t <= positions[0].x {
+        return premultiply(colors[0]);
+    }
+    for (var i = 1u; i < count; i++) {
+        let end = positions[i / 4u][i % 4u];
+        if t <= end {
+            let start = positions[(i - 1u) / 4u][(i - 1u) % 4u];
+            let f = clamp((t - start) / max(end - start, 0.0001), 0., 1.);
+            return gradient_mix(gradient, colors[i - 1u], colors[i], f);
+        }
+    }
+    return premultiply(colors[count - 1u]);
+}
+
+/// The premultiplied fill color at pixel `p`, relative to the center of the
+/// node: the gradient if one is set, otherwise `color`.
+fn fill_color(
+    color: vec4<f32>,
+    gradient: RoundUiGradient,
+    p: vec2<f32>,
+    size: vec2<f32>,
+) -> vec4<f32> {
+    if gradient.count == 0u {
+        return premultiply(color);
+    }
+    return sample_gradient(gradient, gradient_position(gradient, p, size));
+}
+
+/// Texture coordinates of an image fitted into a rect of `rect_size` pixels,
+/// where `p` is relative to the top left corner of the rect, in pixels.
+///
+/// Coordinates outside of `0.0..1.0` are outside of the image, except when
+/// tiling.
+fn image_uv(image: RoundUiImage, p: vec2<f32>, rect_size: vec2<f32>, image_size: vec2<f32>) -> vec2<f32> {
+    let rect = max(rect_size, vec2<f32>(0.0001));
+    let ratio = rect / max(image_size, vec2<f32>(1.));
+    switch image.fit {
+        // cover
+        case 1u: {
+            let displayed = image_size * max(ratio.x, ratio.y);
+            return (p - 0.5 * (rect - displayed)) / displayed;
+        }
+        // contain
+        case 2u: {
+            let displayed = image_size * min(ratio.x, ratio.y);
+            return (p - 0.5 * (rect - displayed)) / displayed;
+        }
+        // tile
+        case 4u: {
+            return p / max(image_size, vec2<f32>(1.));
+        }
+        // stretch
+        default: {
+            return p / rect;
+        }
+    }
+}
+
+/// Coverage of the image at texture coordinates `uv`, which is zero outside
+/// of the image unless it is tiled.
+fn image_coverage(image: RoundUiImage, uv: vec2<f32>) -> f32 {
+    if image.fit == 4u {
+        return 1.;
+    }
+    let inside = step(vec2<f32>(0.), uv) * step(uv, vec2<f32>(1.));
+    return inside.x * inside.y;
+}
+
+/// The premultiplied, tinted color of an image at pixel `p`, relative to the
+/// top left corner of the rect of `rect_size` pixels it is fitted into.
+fn image_color(
+    image_texture: texture_2d<f32>,
+    image_sampler: sampler,
+    image: RoundUiImage,
+    p: vec2<f32>,
+    rect_size: vec2<f32>,
+) -> vec4<f32> {
+    let image_size = vec2<f32>(textureDimensions(image_texture));
+    let uv = image_uv(image, p, rect_size, image_size);
+    // Sample with the derivatives of the unwrapped coordinates, so the seams
+    // between tiles don't pick a blurry mip level.
+    let texel = textureSampleGrad(
+        image_texture,
+        image_sampler,
+        select(uv, fract(uv), image.fit == 4u),
+        dpdx(uv),
+        dpdy(uv),
+    );
+    return premultiply(texel * image.tint) * image_coverage(image, uv);
+}
+
+/// The L4 norm of `p`. The corners are quarter superellipses with an exponent
+/// of 4, so this is the implicit function describing them.
+fn superellipse_norm(p: vec2f) -> f32 {
+    let p2 = p * p;
+    return sqrt(sqrt(p2.x * p2.x + p2.y * p2.y));
+}
+
+/// Point on the corner curve `x^4 + y^4 = r^4`, parameterized by `t` in
+/// `[0, 1]` running from `(r, 0)` to `(0, r)`.
+fn superellipse_point(t: f32, r: f32) -> vec2f {
+    let a = vec2f(1. - t, t);
+    return a * (r / superellipse_norm(a));
+}
+
+
+/// Length of a quarter superellipse with a radius of 1, measured with the
+/// same chords as `superellipse_arc`, so the corners join up exactly.
+const SUPERELLIPSE_QUARTER_LENGTH: f32 = 1.7455408;
+
+/// Number of chords used to measure the length along a superellipse corner.
+const SUPERELLIPSE_ARC_CHORDS: i32 = 8;
+
+/// Length along a corner of radius `r`, from the end on the x axis to the
+/// direction of `q`, which is relative to the center of the corner.
+fn corner_arc(q: vec2<f32>, r: f32, superellipse: bool) -> f32 {
+    if !superellipse {
+        return r * atan2(max(q.y, 0.), max(q.x, 0.));
+    }
+    // the curve parameter pointing in the direction of `q`
+    let t = clamp(q.y / max(q.x + q.y, 0.0001), 0., 1.);
+    var arc = 0.;
+    var prev = superellipse_point(0., r);
+    for (var i = 1; i <= SUPERELLIPSE_ARC_CHORDS; i++) {
+        let c = superellipse_point(t * f32(i) / f32(SUPERELLIPSE_ARC_CHORDS), r);
+        arc += distance(prev, c);
+        prev = c;
+    }
+    return arc;
+}
+
+/// Position along the outline of a rect with half-size `b` and rounded
+/// corners of radius `r`: (bottom-right, top-right, bottom-left, top-left).
+///
+/// Returns `(position, perimeter)` in pixels. The position is measured
+/// clockwise from the start of the top side, following the corner curves, and
+/// `p` is relative to the center of the rect.
+fn perimeter_position(p: vec2<f32>, b: vec2<f32>, r: vec4<f32>, superellipse: bool) -> vec2<f32> {
+    let radius = clamp(r, vec4<f32>(0.), vec4<f32>(min(b.x, b.y)));
+    let br = radius.x;
+    let tr = radius.y;
+    let bl = radius.z;
+    let tl = radius.w;
+    let quarter = select(HALF_PI, SUPERELLIPSE_QUARTER_LENGTH, superellipse);
+
+    // lengths of the sides, and of the corners
+    let top = 2. * b.x - tl - tr;
+    let right = 2. * b.y - tr - br;
+    let bottom = 2. * b.x - br - bl;
+    let left = 2. * b.y - bl - tl;
+    let corners = quarter * radius;
+
+    // distance along the outline to the start of each side
+    let right_start = top + corners.y;
+    let bottom_start = right_start + right + corners.x;
+    let left_start = bottom_start + bottom + corners.z;
+    let perimeter = left_start + left + corners.w;
+
+    // the corner of the quadrant containing `p`
+    let r_xy = select(radius.zw, radius.xy, p.x > 0.);
+    let corner = select(r_xy.y, r_xy.x, p.y > 0.);
+    let q = abs(p) - b + corner;
+
+    var position = 0.;
+    if q.x > 0. && q.y > 0. {
+        // Corners run from the end on the x axis (a vertical side) to the end
+        // on the y axis (a horizontal side).
+        let arc = corner_arc(q, corner, superellipse);
+        let full = quarter * corner;
+        if p.x > 0. && p.y <= 0. {
+            position = top + full - arc;
+        } else if p.x > 0. {
+            position = right_start + right + arc;
+        } else if p.y > 0. {
+            position = bottom_start + bottom + full - arc;
+        } else {
+            position = left_start + left + arc;
+        }
+    } else if b.x - abs(p.x) < b.y - abs(p.y) {
+        // closest to a vertical side
+        if p.x > 0. {
+            position = right_start + p.y + b.y - tr;
+        } else {
+            position = left_start + b.y - bl - p.y;
+        }
+    } else {
+        // closest to a horizontal side
+        if p.y > 0. {
+            position = bottom_start + b.x - br - p.x;
+        } else {
+            position = p.x + b.x - tl;
+        }
+    }
+    return vec2<f32>(position, perimeter);
+}
+
+/// The thinnest of the non-zero `sides` of a border, or zero if there is no
+/// border.
+fn thinnest_side(sides: vec4<f32>) -> f32 {
+    let thickness = select(vec4<f32>(1e9), sides, sides > vec4<f32>(0.));
+    let thinnest = min(min(thickness.x, thickness.y), min(thickness.z, thickness.w));
+    return select(0., thinnest, thinnest < 1e9);
+}
+
+/// Coverage of the pattern of a dashed or dotted border style, which is 1 for
+/// solid borders.
+///
+/// `position` and `perimeter` are the result of `perimeter_position` for the
+/// center line of the border, and `d` is the signed distance to that line.
+/// The pattern is stretched slightly so a whole number of dashes or dots fit
+/// around the border.
+fn border_style_coverage(
+    style: RoundUiBorderStyle,
+    position: f32,
+    perimeter: f32,
+    d: f32,
+    thickness: f32,
+    aa: f32,
+) -> f32 {
+    if style.style == 0u {
+        return 1.;
+    }
+
+    // dots are as wide as the border
+    let is_dotted = style.style == 2u;
+    let dash_length = select(max(style.length, 0.), 0., is_dotted);
+    let period = max(dash_length + max(style.gap, 0.) + select(0., thickness, is_dotted), 0.0001);
+    let fitted = perimeter / max(round(perimeter / period), 1.);
+    let dash = dash_length * fitted / period;
+
+    // distance along the border from the center of the nearest dash
+    let t = position - style.phase - 0.5 * dash;
+    let along = t - fitted * round(t / fitted);
+
+    if is_dotted {
+        return coverage(length(vec2<f32>(along, d)) - 0.5 * thickness, aa);
+    }
+    return coverage(abs(along) - 0.5 * dash, aa);
+}
+
+/// Adds a small amount of noise to a straight alpha color, to break up the
+/// banding of smooth gradients in 8-bit render targets.
+fn dither(color: vec4<f32>, frag_coord: vec2<f32>) -> vec4<f32> {
+    // interleaved gradient noise, in the range -0.5 to 0.5
+    let noise = fract(52.9829189 * fract(dot(frag_coord, vec2<f32>(0.06711056, 0.00583715)))) - 0.5;
+    // apply one step of 8-bit noise in (approximately) sRGB space, where the
+    // quantization happens
+    let srgb = pow(max(color.rgb, vec3<f32>(0.)), vec3<f32>(1. / 2.2)) + noise / 255.;
+    return vec4<f32>(pow(max(srgb, vec3<f32>(0.)), vec3<f32>(2.2)), color.a);
+}
+
+fn premultiply(color: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(color.rgb * color.a, color.a);
+}
+
+fn unpremultiply(color: vec4<f32>) -> vec4<f32> {
+    if color.a <= 0. {
+        return vec4<f32>(0.);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
@@ -1,4 +1,25 @@
-use bevy::{asset::load_internal_asset, prelude::*, render::render_resource::*};
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    render::{render_asset::RenderAssets, render_resource::*, texture::GpuImage},
+};
+
+use crate::{
+    cooldown::{add_cooldown_systems, RoundUiSweepMaskMaterial},
+    interaction::add_interaction_systems,
+    managed::{add_managed_style_systems, color_components, float_bits, RoundUiManagedStyle},
+    padding::{add_padding_systems, RoundUiPaddedMaterial, RoundUiPaddingMode},
+    responsive::{add_responsive_systems, RoundUiResponsiveMaterial},
+    shared::{
+        configure_system_sets, load_shared_shaders, RoundUiBorderStyleUniform,
+        RoundUiGradientUniform, RoundUiImageUniform, RoundUiSweepMaskUniform,
+    },
+    transition::{
+        add_transition_systems, mix_color, mix_f32, mix_outline, mix_shadow,
+        RoundUiTransitionMaterial,
+    },
+    types::*,
+};
 
 pub const SUPERELLIPSE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(84071151984186645753);
 
@@ -7,6 +28,8 @@ pub struct SuperellipseMaterialPlugin;
 
 impl Plugin for SuperellipseMaterialPlugin {
     fn build(&self, app: &mut App) {
+        load_shared_shaders(app);
+        configure_system_sets(app);
         load_internal_asset!(
             app,
             SUPERELLIPSE_SHADER_HANDLE,
@@ -15,50 +38,353 @@ impl Plugin for SuperellipseMaterialPlugin {
         );
 
         app.add_plugins(UiMaterialPlugin::<SuperellipseUiMaterial>::default());
+        add_responsive_systems::<SuperellipseUiMaterial>(app);
+        add_managed_style_systems::<SuperellipseUiStyle>(app);
+        add_interaction_systems::<SuperellipseUiMaterial>(app);
+        add_transition_systems::<SuperellipseUiMaterial>(app);
+        add_cooldown_systems::<SuperellipseUiMaterial>(app);
+        add_padding_systems::<SuperellipseUiMaterial>(app);
     }
 }
 
-/// UI Material that renders a rounded rect using an approximate superellipse SDF function, with an optional border.
+/// UI Material that renders a rounded rect with superellipse corners, with an optional border and offset.
 ///
-/// NOTE: A minimum border radius is enforced by the shader, due to a limitation in the approximate superellipse SDF
-/// function. If one of your border radii is too small, is will appear larger.
+/// Each corner is a quarter of a superellipse (with an exponent of 4), which gives a smoother transition into the
+/// straight sides than the circular corners of `RoundRectUiMaterial`.
 #[derive(AsBindGroup, Asset, Debug, Clone, Reflect)]
+#[uniform(0, SuperellipseUiMaterialUniform)]
 #[reflect(Default, Debug)]
 pub struct SuperellipseUiMaterial {
     /// The background color of the material
-    #[uniform(0)]
     pub background_color: LinearRgba,
 
+    /// Gradient used for the background instead of `background_color`
+    pub background_gradient: Option<RoundUiGradient>,
+
+    /// Image drawn on top of the background, clipped to the shape. Render target images can be used to show the
+    /// output of a camera.
+    #[texture(1)]
+    #[sampler(2)]
+    pub image: Option<Handle<Image>>,
+
+    /// How the image is fitted into the area inside the border
+    pub image_fit: RoundUiImageFit,
+
+    /// Color the image is multiplied by
+    pub image_tint: LinearRgba,
+
     /// The border color of the material
-    #[uniform(0)]
     pub border_color: LinearRgba,
 
+    /// Gradient used for the border instead of `border_color`
+    pub border_gradient: Option<RoundUiGradient>,
+
+    /// Whether the border is solid, dashed or dotted
+    pub border_style: RoundUiBorderStyle,
+
     /// The border radius of each corner
     /// E.g. Vec4::new(bottom_right, top_right, bottom_left, top_left)
     ///
-    /// NOTE: A minimum border radius is enforced by the shader, due to a limitation in the approximate superellipse
-    /// SDF function. If one of your border radii is too small, is will appear larger.
-    #[uniform(0)]
+    /// Values use the same units as `RoundRectUiMaterial::border_radius`: each corner curve spans half its value, in
+    /// pixels, along both of its sides. Values are clamped to the shortest side of the node, and a value of `0.0` gives
+    /// a sharp corner.
     pub border_radius: Vec4,
 
-    /// The thickness of the border
-    #[uniform(0)]
-    pub border_thickness: f32,
+    /// The thickness of the border along each side, in pixels, drawn inside the edge of the node
+    /// E.g. Vec4::new(top, left, bottom, right)
+    ///
+    /// Use `RoundUiOffset` to build it, e.g. `RoundUiOffset::all(4.0).into()` for an even border.
+    pub border_thickness: Vec4,
+
+    /// The offset along each side of the shape, like `RoundRectUiMaterial::offset`
+    /// E.g. Vec4::new(top, left, bottom, right)
+    ///
+    /// The face of the shape is shrunk away from each side by its offset, and drawn over a base of the border color
+    /// filling the node. E.g. `RoundUiOffset::bottom(10.0).into()` gives a raised 3D look. The border, image and inset
+    /// shadow are drawn inside the face.
+    pub offset: Vec4,
+
+    /// Width of the anti-aliased edges of the shape, in pixels. See [edge softness](crate#edge-softness).
+    pub edge_softness: f32,
+
+    /// Drop shadow drawn behind the material. It is rendered outside the bounds of the node if needed.
+    pub shadow: RoundUiShadow,
+
+    /// Inner shadow drawn inside the border, on top of the background.
+    pub inset_shadow: RoundUiShadow,
+
+    /// Outline drawn around the outside of the material, following its corner radii. It is rendered outside the bounds
+    /// of the node, so it doesn't affect the layout.
+    pub outline: RoundUiOutline,
+
+    /// Color of the progress fill, drawn over the background
+    pub fill_color: LinearRgba,
+
+    /// How much of the area inside the border the progress fill covers, from `0.0` (empty) to `1.0` (full), e.g. for health bars.
+    ///
+    /// The fill has rounded corners of its own, so its leading edge keeps a rounded cap, and it is clipped to the shape
+    /// so it stays inside it at any fraction.
+    pub fill_fraction: f32,
+
+    /// The side the progress fill starts from
+    pub fill_direction: RoundUiFillDirection,
+
+    /// "Clock wipe" overlay drawn over the material and clipped to its shape, e.g. for the cooldown of an ability
+    /// button. It sweeps around the center of the face.
+    pub sweep_mask: RoundUiSweepMask,
 }
 
 impl Default for SuperellipseUiMaterial {
     fn default() -> Self {
         Self {
             background_color: LinearRgba::WHITE,
+            background_gradient: None,
+            image: None,
+            image_fit: RoundUiImageFit::default(),
+            image_tint: LinearRgba::WHITE,
             border_color: LinearRgba::NONE,
+            border_gradient: None,
+            border_style: RoundUiBorderStyle::default(),
             border_radius: Vec4::splat(0.),
-            border_thickness: 0.,
+            border_thickness: Vec4::splat(0.),
+            offset: Vec4::splat(0.),
+            edge_softness: 0.,
+            shadow: RoundUiShadow::default(),
+            inset_shadow: RoundUiShadow::default(),
+            outline: RoundUiOutline::default(),
+            fill_color: LinearRgba::NONE,
+            fill_fraction: 0.,
+            fill_direction: RoundUiFillDirection::default(),
+            sweep_mask: RoundUiSweepMask::default(),
+        }
+    }
+}
+
+pub use shader_types::SuperellipseUiMaterialUniform;
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// The GPU representation of a `SuperellipseUiMaterial`.
+    #[derive(Clone, Default, ShaderType)]
+    pub struct SuperellipseUiMaterialUniform {
+        pub(super) background_color: LinearRgba,
+        pub(super) border_color: LinearRgba,
+        pub(super) border_radius: Vec4,
+        pub(super) border_thickness: Vec4,
+        pub(super) edge_softness: f32,
+        pub(super) shadow: RoundUiShadow,
+        pub(super) inset_shadow: RoundUiShadow,
+        pub(super) background_gradient: RoundUiGradientUniform,
+        pub(super) border_gradient: RoundUiGradientUniform,
+        pub(super) image: RoundUiImageUniform,
+        pub(super) border_style: RoundUiBorderStyleUniform,
+        pub(super) offset: Vec4,
+        pub(super) outline: RoundUiOutline,
+        pub(super) fill_color: LinearRgba,
+        pub(super) fill_fraction: f32,
+        pub(super) fill_direction: u32,
+        pub(super) sweep_mask: RoundUiSweepMaskUniform,
+    }
+}
+
+impl AsBindGroupShaderType<SuperellipseUiMaterialUniform> for SuperellipseUiMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> SuperellipseUiMaterialUniform {
+        SuperellipseUiMaterialUniform {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            border_radius: self.border_radius,
+            border_thickness: self.border_thickness,
+            edge_softness: self.edge_softness,
+            shadow: self.shadow,
+            inset_shadow: self.inset_shadow,
+            background_gradient: self.background_gradient.as_ref().into(),
+            border_gradient: self.border_gradient.as_ref().into(),
+            image: RoundUiImageUniform::new(self.image.as_ref(), self.image_fit, self.image_tint),
+            border_style: self.border_style.into(),
+            offset: self.offset,
+            outline: self.outline,
+            fill_color: self.fill_color,
+            fill_fraction: self.fill_fraction,
+            fill_direction: self.fill_direction.into(),
+            sweep_mask: self.sweep_mask.into(),
         }
     }
 }
 
 impl UiMaterial for SuperellipseUiMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SUPERELLIPSE_SHADER_HANDLE.into()
+    }
+
     fn fragment_shader() -> ShaderRef {
         SUPERELLIPSE_SHADER_HANDLE.into()
     }
 }
+
+impl RoundUiResponsiveMaterial for SuperellipseUiMaterial {
+    fn border_radius(&self) -> Vec4 {
+        self.border_radius
+    }
+
+    fn set_border_radius(&mut self, border_radius: Vec4) {
+        self.border_radius = border_radius;
+    }
+
+    fn offset(&self) -> Vec4 {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Vec4) {
+        self.offset = offset;
+    }
+
+    fn border_thickness(&self) -> Option<Vec4> {
+        Some(self.border_thickness)
+    }
+
+    fn set_border_thickness(&mut self, border_thickness: Vec4) {
+        self.border_thickness = border_thickness;
+    }
+}
+
+impl RoundUiSweepMaskMaterial for SuperellipseUiMaterial {
+    fn sweep_mask(&self) -> RoundUiSweepMask {
+        self.sweep_mask
+    }
+
+    fn set_sweep_mask(&mut self, sweep_mask: RoundUiSweepMask) {
+        self.sweep_mask = sweep_mask;
+    }
+}
+
+impl RoundUiTransitionMaterial for SuperellipseUiMaterial {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            background_color: mix_color(self.background_color, target.background_color, t),
+            border_color: mix_color(self.border_color, target.border_color, t),
+            image_tint: mix_color(self.image_tint, target.image_tint, t),
+            border_radius: self.border_radius.lerp(target.border_radius, t),
+            border_thickness: self.border_thickness.lerp(target.border_thickness, t),
+            offset: self.offset.lerp(target.offset, t),
+            edge_softness: mix_f32(self.edge_softness, target.edge_softness, t),
+            shadow: mix_shadow(self.shadow, target.shadow, t),
+            inset_shadow: mix_shadow(self.inset_shadow, target.inset_shadow, t),
+            outline: mix_outline(self.outline, target.outline, t),
+            fill_color: mix_color(self.fill_color, target.fill_color, t),
+            fill_fraction: mix_f32(self.fill_fraction, target.fill_fraction, t),
+            ..target.clone()
+        }
+    }
+}
+
+#[cfg(feature = "round_rect")]
+impl crate::focus::RoundUiFocusRingMaterial for SuperellipseUiMaterial {
+    fn focus_ring_radius(&self) -> Vec4 {
+        // A circular corner with about half the span of a superellipse corner reaches as far into the corner
+        self.border_radius * 0.5
+    }
+}
+
+impl RoundUiPaddedMaterial for SuperellipseUiMaterial {
+    fn padding(&self, mode: RoundUiPaddingMode) -> RoundUiOffset {
+        let offset: RoundUiOffset = self.offset.into();
+        let border_thickness: RoundUiOffset = self.border_thickness.into();
+        let offset = offset + border_thickness;
+        match mode {
+            RoundUiPaddingMode::Offset => offset,
+            RoundUiPaddingMode::Corners => {
+                let border: RoundUiBorder = self.border_radius.into();
+                RoundUiOffset {
+                    left: offset.left + border.top_left.max(border.bottom_left),
+                    right: offset.right + border.top_right.max(border.bottom_right),
+                    top: offset.top + border.top_left.max(border.top_right),
+                    bottom: offset.bottom + border.bottom_left.max(border.bottom_right),
+                }
+            }
+        }
+    }
+}
+
+impl SuperellipseUiMaterial {
+    pub fn get_padding(&self) -> UiRect {
+        self.padding(RoundUiPaddingMode::Corners).into()
+    }
+}
+
+/// A simple superellipse description for a node, which the plugin turns into a `SuperellipseUiMaterial`.
+///
+/// Nodes with identical styles share the same material, which is updated when the style changes and freed once no
+/// node uses it. The node doesn't need a material of its own, a `NodeBundle` is enough.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_round_ui::prelude::*;
+///
+/// fn spawn_card(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         SuperellipseUiStyle {
+///             background_color: LinearRgba::WHITE,
+///             border_color: LinearRgba::BLACK,
+///             border_radius: RoundUiBorder::all(10.),
+///             border_thickness: RoundUiOffset::all(2.),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct SuperellipseUiStyle {
+    /// The background color of the material
+    pub background_color: LinearRgba,
+    /// The border color of the material
+    pub border_color: LinearRgba,
+    /// The border radius of each corner
+    pub border_radius: RoundUiBorder,
+    /// The thickness of the border along each side
+    pub border_thickness: RoundUiOffset,
+}
+
+impl Default for SuperellipseUiStyle {
+    fn default() -> Self {
+        Self {
+            background_color: LinearRgba::WHITE,
+            border_color: LinearRgba::NONE,
+            border_radius: RoundUiBorder::default(),
+            border_thickness: RoundUiOffset::default(),
+        }
+    }
+}
+
+impl RoundUiManagedStyle for SuperellipseUiStyle {
+    type Material = SuperellipseUiMaterial;
+    type Key = [u32; 16];
+
+    fn key(&self) -> Self::Key {
+        let border_radius: Vec4 = self.border_radius.into();
+        let border_thickness: Vec4 = self.border_thickness.into();
+        let mut key = [0.; 16];
+        key[0..4].copy_from_slice(&color_components(self.background_color));
+        key[4..8].copy_from_slice(&color_components(self.border_color));
+        key[8..12].copy_from_slice(&border_radius.to_array());
+        key[12..16].copy_from_slice(&border_thickness.to_array());
+        float_bits(key)
+    }
+
+    fn material(&self) -> Self::Material {
+        SuperellipseUiMaterial {
+            background_color: self.background_color,
+            border_color: self.border_color,
+            border_radius: self.border_radius.into(),
+            border_thickness: self.border_thickness.into(),
+            ..default()
+        }
+    }
+}
@@ -1,85 +1,305 @@
 #import bevy_ui::ui_vertex_output::UiVertexOutput
+#import bevy_round_ui::shared::{
+    RoundUiBorderStyle,
+    RoundUiGradient,
+    RoundUiImage,
+    RoundUiOutline,
+    RoundUiShadow,
+    RoundUiSweepMask,
+    blend_over,
+    border_style_coverage,
+    coverage,
+    dither,
+    edge_width,
+    expand_vertex,
+    fill_color,
+    image_color,
+    inset_shadow_coverage,
+    outline_color,
+    outline_extents,
+    perimeter_position,
+    premultiply,
+    progress_rect,
+    shadow_coverage,
+    shadow_extents,
+    superellipse_norm,
+    superellipse_point,
+    sweep_mask_color,
+    thinnest_side,
+    unpremultiply,
+}
 
 struct SuperellipseUiMaterial {
     /// Background color
     @location(0) background_color: vec4<f32>,
     /// Border color
     @location(1) border_color: vec4<f32>,
-    /// border-radius of each corner:
-    // (bottom-right, top-right, bottom-left, top-left)
+    /// border-radius of each corner, in the doubled units of
+    /// `RoundRectUiMaterial`: (bottom-right, top-right, bottom-left, top-left)
     @location(2) border_radius: vec4<f32>,
-    /// Border thickness: ignored if `border_color.a == 0.0`
-    @location(3) border_thickness: f32,
+    /// Border thickness of each side in pixels: (top, left, bottom, right)
+    @location(3) border_thickness: vec4<f32>,
+    /// Width of the anti-aliased edge, in pixels
+    @location(4) edge_softness: f32,
+    /// Drop shadow drawn behind the shape
+    @location(5) shadow: RoundUiShadow,
+    /// Inner shadow drawn inside the border
+    @location(6) inset_shadow: RoundUiShadow,
+    /// Gradients used instead of `background_color` and `border_color`
+    @location(7) background_gradient: RoundUiGradient,
+    @location(8) border_gradient: RoundUiGradient,
+    /// Image drawn on top of the background
+    @location(9) image: RoundUiImage,
+    /// Solid, dashed or dotted border
+    @location(10) border_style: RoundUiBorderStyle,
+    /// Offset of each side of the face in pixels: (top, left, bottom, right)
+    @location(11) offset: vec4<f32>,
+    /// Outline drawn outside the shape
+    @location(12) outline: RoundUiOutline,
+    /// Progress fill drawn over the background, inside the border:
+    /// `fill_color`, `fill_fraction` and `fill_direction`, renamed so they
+    /// don't clash with the imported `fill_color` function
+    @location(13) progress_color: vec4<f32>,
+    @location(14) progress_fraction: f32,
+    /// Side the fill starts from, matching `RoundUiFillDirection`
+    @location(15) progress_direction: u32,
+    /// "Clock wipe" overlay drawn over the shape
+    @location(16) sweep_mask: RoundUiSweepMask,
 }
 
 @group(1) @binding(0)
 var<uniform> input: SuperellipseUiMaterial;
 
-/// Adapted from:
-///   https://www.shadertoy.com/view/4cG3R1
+@group(1) @binding(1)
+var image_texture: texture_2d<f32>;
+@group(1) @binding(2)
+var image_sampler: sampler;
+
+/// The distance in pixels that each corner spans along its sides. Like the
+/// radii of `RoundRectUiMaterial`, `border_radius` is twice that distance, so
+/// the same values give corners of a similar size.
+fn corner_span() -> vec4f {
+    return 0.5 * input.border_radius;
+}
+
+/// Number of bisection steps used when searching for the closest point on a
+/// superellipse corner. Each step halves the search interval.
+const CORNER_ITERATIONS: i32 = 12;
+
+/// Signed distance from `p` to a quarter superellipse `x^4 + y^4 = r^4` in the
+/// positive quadrant.
 ///
-/// Related article:
-///   https://iquilezles.org/articles/roundedboxes/
-fn approx_sd_super_ellipse(p: vec2f, b: vec2f, r: vec4f) -> f32 {
+/// The closest point is found by bisecting the curve parameter until the
+/// curve normal points at `p`. The parameterization has no singularities, so
+/// this stays accurate for any radius.
+fn sd_superellipse_corner(p: vec2f, r: f32) -> f32 {
+    if r <= 0. {
+        return length(p);
+    }
+
+    var lo = 0.;
+    var hi = 1.;
+    for (var i = 0; i < CORNER_ITERATIONS; i++) {
+        let t = 0.5 * (lo + hi);
+        let c = superellipse_point(t, r);
+        // the curve normal is proportional to the gradient of x^4 + y^4
+        let n = c * c * c;
+        let side = (p.x - c.x) * n.y - (p.y - c.y) * n.x;
+        if side > 0. {
+            hi = t;
+        } else {
+            lo = t;
+        }
+    }
+
+    let d = length(p - superellipse_point(0.5 * (lo + hi), r));
+    if superellipse_norm(p) > r {
+        return d;
+    }
+
+    // Inside the curve, the ends of the straight sides are also candidates
+    // for the closest point.
+    return -min(d, min(length(p - vec2f(r, 0.)), length(p - vec2f(0., r))));
+}
+
+/// Signed distance, in pixels, to a rect with half-size `b` whose corners are
+/// quarter superellipses spanning `r` pixels along each side.
+fn sd_superellipse_rect(p: vec2f, b: vec2f, r: vec4f) -> f32 {
     // select corner radius
-    var r_xy = select(r.zw, r.xy, p.x > 0.);
-    var n = select(r_xy.y, r_xy.x, p.y > 0.);
-
-    let abs_p = abs(p);
-
-    // really bad, cheap linearliation of the basic implicit formula
-    n = 2.0 / n;
-    let w = pow(abs_p.x / b.x, n) + pow(abs_p.y / b.y, n);
-    let kb = 2.0 * n - 2.0;
-    let ka = 1.0 - 1.0 / n;
-    let kc = 2.0 * n;
-    return (w - pow(w, ka)) * inverseSqrt(pow(abs_p.x, kb) / pow(b.x, kc) + pow(abs_p.y, kb) / pow(b.y, kc));
+    let r_xy = select(r.zw, r.xy, p.x > 0.);
+    let radius = clamp(select(r_xy.y, r_xy.x, p.y > 0.), 0., min(b.x, b.y));
+
+    let q = abs(p) - b + radius;
+    if q.x > 0. && q.y > 0. {
+        return sd_superellipse_corner(q, radius);
+    }
+    return min(max(q.x, q.y), 0.) + length(max(q, vec2f(0.))) - radius;
+}
+
+/// Signed distance, in pixels, to the area inside a border with the given
+/// thickness on each side: (top, left, bottom, right).
+///
+/// The part of the thickness shared by all sides is an exact inset of the
+/// outline. Sides that are thicker than that move the inner edge further in,
+/// shrinking the neighbouring corners like the offset of `RoundRectUiMaterial`.
+fn sd_inner(p: vec2f, size: vec2f, sides: vec4f) -> f32 {
+    let uniform = min(min(sides.x, sides.y), min(sides.z, sides.w));
+    let extra = sides - uniform;
+
+    let center = 0.5 * vec2f(extra.y - extra.w, extra.x - extra.z);
+    let half_size = 0.5 * (size - vec2f(extra.y + extra.w, extra.x + extra.z));
+    // corners: (bottom-right, top-right, bottom-left, top-left)
+    let shrink = vec4f(
+        min(extra.z, extra.w),
+        min(extra.x, extra.w),
+        min(extra.z, extra.y),
+        min(extra.x, extra.y),
+    );
+    let radius = max(corner_span() - shrink, vec4f(0.));
+    return sd_superellipse_rect(p - center, half_size, radius) + uniform;
+}
+
+/// Signed distance, in pixels, to the progress fill: a rect with the same
+/// corners covering `progress_fraction` of the area inside the border, so its
+/// leading edge has a rounded cap of its own. It is clipped to the area inside
+/// the border, so it stays inside the shape at any fraction.
+fn sd_fill(p: vec2f, size: vec2f, sides: vec4f) -> f32 {
+    let inner_center = 0.5 * vec2f(sides.y - sides.w, sides.x - sides.z);
+    let inner_size = size - vec2f(sides.y + sides.w, sides.x + sides.z);
+    let fill = progress_rect(0.5 * inner_size, input.progress_fraction, input.progress_direction);
+    let uniform = min(min(sides.x, sides.y), min(sides.z, sides.w));
+    let radius = max(corner_span() - uniform, vec4f(0.));
+    let d_fill = sd_superellipse_rect(p - inner_center - fill.xy, fill.zw, radius);
+    return max(d_fill, sd_inner(p, size, sides));
+}
+
+@vertex
+fn vertex(
+    @location(0) vertex_position: vec3<f32>,
+    @location(1) vertex_uv: vec2<f32>,
+    @location(2) size: vec2<f32>,
+    @location(3) border_widths: vec4<f32>,
+) -> UiVertexOutput {
+    // grow the quad so the shadow and outline can be drawn outside the node
+    return expand_vertex(
+        vertex_position,
+        vertex_uv,
+        size,
+        border_widths,
+        max(shadow_extents(input.shadow), outline_extents(input.outline)),
+    );
 }
 
 @fragment
 fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
     // compute whether we should display the border
-    let is_border = input.border_thickness > 0. && input.border_color.a > 0.;
+    let is_border = any(input.border_thickness > vec4f(0.))
+        && (input.border_color.a > 0. || input.border_gradient.count > 0u);
+    let sides = select(vec4f(0.), max(input.border_thickness, vec4f(0.)), is_border);
 
-    // adjust size by subtracting the border thickness
-    var size = in.size;
-    if is_border {
-        size -= vec2f(input.border_thickness);
-    }
+    // adjust UVs around the middle of the rect, in pixels
+    let p = (in.uv - 0.5) * in.size;
 
-    // adjust UVs around the middle of the rect, and convert to pixel
-    // coordinates.
-    let uv = in.uv * in.size * 2.0 - in.size;
+    // The face is the node shrunk by the offset, drawn over a base of the
+    // border color which fills the whole node. Everything inside the face is
+    // positioned relative to it.
+    let offset = max(input.offset, vec4f(0.));
+    let has_offset = any(offset > vec4f(0.));
+    let face_center = 0.5 * vec2f(offset.y - offset.w, offset.x - offset.z);
+    let face_size = in.size - vec2f(offset.y + offset.w, offset.x + offset.z);
+    let fp = p - face_center;
 
-    // define the shortest length of the image, as we need it to adjust the UV,
-    // size and border coordinates.
-    let min_size = min(in.size.x, in.size.y);
+    // Compute signed distance in pixels
+    let d_face = sd_superellipse_rect(fp, face_size * 0.5, corner_span());
+    let d = min(d_face, sd_superellipse_rect(p, in.size * 0.5, corner_span()));
 
-    // IMPORTANT: Minimum border radius of 0.2, otherwise the approximation
-    // behaves strangely.
-    let border_radius = max(input.border_radius / min_size, vec4f(0.2));
+    // // Debug: Show distance
+    // var col = vec3f(1.0) - sign(d) * vec3f(0.1, 0.4, 0.7);
+    // col *= 1.0 - exp(-0.05 * abs(d));
+    // col *= 0.8 + 0.2 * cos(0.5 * d);
+    // return vec4f(col, 1.0);
 
-    // Compute signed distance
-    let d = approx_sd_super_ellipse(
-        uv / vec2f(min_size),
-        size / vec2f(min_size),
-        border_radius,
-    );
+    let aa = edge_width(d, input.edge_softness);
 
-    // define the alpha and color values depending on the distance sign.
-    let alpha = select(input.background_color.a, 0., d > 0.);
-    var col: vec3f = select(input.background_color.rgb, vec3f(0.), d > 0.0);
+    // Coverage of the whole shape, and of the area inside the border.
+    let alpha = coverage(d, aa);
+    var inner = 1.;
+    if is_border || has_offset {
+        inner = coverage(sd_inner(fp, face_size, sides), aa);
+    }
 
-    // // Debug: Show distance
-    // col *= 1.0 - exp(-6.0 * abs(d));
-    // col *= 0.8 + 0.2 * cos(150.0 * d);
-
-    // Apply border color
-    var result = vec4f(col, alpha);
-    if is_border {
-        let border_thickness_uv = input.border_thickness / min_size;
-        result = mix(result, input.border_color, 1.0 - smoothstep(0.0, border_thickness_uv, abs(d)));
+    // Draw the image and the inset shadow on top of the background, inside the
+    // border.
+    var background = fill_color(input.background_color, input.background_gradient, p, in.size);
+    if input.image.fit != 0u {
+        // fit the image into the area inside the border
+        let inner_p = fp + 0.5 * face_size - sides.yx;
+        let inner_size = face_size - vec2f(sides.y + sides.w, sides.x + sides.z);
+        let image = image_color(image_texture, image_sampler, input.image, inner_p, inner_size);
+        background = blend_over(image, background);
+    }
+    if input.progress_fraction > 0. && input.progress_color.a > 0. {
+        let fill = premultiply(input.progress_color) * coverage(sd_fill(fp, face_size, sides), aa);
+        background = blend_over(fill, background);
+    }
+    if input.inset_shadow.color.a > 0. {
+        // the hole follows the inner edge of the border
+        let d_hole = sd_inner(fp - input.inset_shadow.offset, face_size, sides)
+            + input.inset_shadow.spread;
+        let inset = premultiply(input.inset_shadow.color)
+            * inset_shadow_coverage(d_hole, input.inset_shadow.blur, aa);
+        background = blend_over(inset, background);
+    }
+
+    // Blend between the border and background colors using premultiplied
+    // alpha, so a transparent color doesn't darken the seam.
+    let border = fill_color(input.border_color, input.border_gradient, p, in.size);
+    var dashes = 1.;
+    let thickness = thinnest_side(sides);
+    if thickness > 0. && input.border_style.style != 0u {
+        // dashes and dots follow the center line of the thinnest side of the
+        // border around the face
+        let half_thickness = 0.5 * thickness;
+        let path = perimeter_position(
+            fp,
+            0.5 * face_size - half_thickness,
+            corner_span() - half_thickness,
+            true,
+        );
+        dashes = border_style_coverage(
+            input.border_style,
+            path.x,
+            path.y,
+            d_face + half_thickness,
+            thickness,
+            aa,
+        );
     }
+    var color = mix(border * dashes, background, inner) * alpha;
 
+    // Draw the sweep mask over the whole shape, sweeping around the center of
+    // the face.
+    color = blend_over(sweep_mask_color(input.sweep_mask, fp, aa) * alpha, color);
+
+    // Draw the shadow behind the shape. Like CSS box shadows, it is hidden
+    // underneath the shape even if the shape is transparent.
+    var shadow = vec4f(0.);
+    if input.shadow.color.a > 0. {
+        let shadow_p = p - input.shadow.offset;
+        let d_shadow = min(
+            sd_superellipse_rect(shadow_p - face_center, face_size * 0.5, corner_span()),
+            sd_superellipse_rect(shadow_p, in.size * 0.5, corner_span()),
+        ) - input.shadow.spread;
+        shadow = premultiply(input.shadow.color)
+            * shadow_coverage(d_shadow, input.shadow.blur, aa)
+            * (1. - alpha);
+    }
+
+    // Draw the outline on top of everything else.
+    let outline = outline_color(input.outline, d, aa);
+    var result = unpremultiply(blend_over(outline, color + shadow));
+    if input.background_gradient.count > 0u || input.border_gradient.count > 0u {
+        result = dither(result, in.position.xy);
+    }
     return result;
 }
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// Creates a headless app with the asset type `M`, for testing the systems of a material.
+pub(crate) fn material_test_app<M: Asset>() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<M>();
+    app
+}
+
+/// Adds `material` to the assets of the app.
+pub(crate) fn add_material<M: Asset>(app: &mut App, material: M) -> Handle<M> {
+    app.world_mut().resource_mut::<Assets<M>>().add(material)
+}
+
+/// The material handle of `entity`.
+pub(crate) fn material_handle<M: Asset>(app: &App, entity: Entity) -> Handle<M> {
+    app.world().get::<Handle<M>>(entity).unwrap().clone()
+}
+
+/// The material that `handle` points to.
+pub(crate) fn material<'a, M: Asset>(app: &'a App, handle: &Handle<M>) -> &'a M {
+    app.world().resource::<Assets<M>>().get(handle).unwrap()
+}
@@ -0,0 +1,391 @@
+use bevy::{math::cubic_splines::CubicSegment, prelude::*, utils::HashSet};
+
+use crate::types::*;
+
+/// Easing curves for `RoundUiTransition`.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq)]
+pub enum RoundUiEasing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly and speeds up (cubic).
+    EaseIn,
+    /// Starts quickly and slows down (cubic).
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly (cubic).
+    EaseInOut,
+    /// A cubic bezier curve through `(0, 0)`, the two control points and `(1, 1)`, like the CSS `cubic-bezier()`
+    /// function.
+    CubicBezier(Vec2, Vec2),
+}
+
+impl RoundUiEasing {
+    /// Maps the linear progress `t` of a transition, from `0.0` to `1.0`, to the eased progress.
+    ///
+    /// ```
+    /// use bevy_round_ui::prelude::RoundUiEasing;
+    ///
+    /// assert_eq!(RoundUiEasing::Linear.ease(0.25), 0.25);
+    /// assert_eq!(RoundUiEasing::EaseInOut.ease(0.5), 0.5);
+    /// assert!(RoundUiEasing::EaseOut.ease(0.25) > 0.25);
+    /// ```
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1. - (1. - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Self::CubicBezier(p1, p2) => CubicSegment::new_bezier(*p1, *p2).ease(t),
+        }
+    }
+}
+
+/// Animates the changes to the material of a node, instead of switching materials instantly.
+///
+/// When the material handle of the node changes, e.g. because of a `RoundUiInteractionStyle`, the node keeps its own
+/// copy of the material and interpolates it towards the new material over `duration` seconds. Colors are interpolated
+/// in the Oklab color space, along with the border radius, offset or border thickness, edge softness and shadows.
+/// Other properties, such as gradients and images, switch at the start of the transition.
+///
+/// Each node is animated separately, so nodes sharing the same materials don't animate together.
+///
+/// # Example:
+///
+/// ```
+/// use bevy_round_ui::prelude::*;
+///
+/// let transition = RoundUiTransition::new(0.15).with_easing(RoundUiEasing::EaseInOut);
+/// assert_eq!(transition.duration, 0.15);
+/// ```
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+pub struct RoundUiTransition {
+    /// The duration of the transition, in seconds
+    pub duration: f32,
+    /// The easing curve of the transition
+    pub easing: RoundUiEasing,
+}
+
+impl RoundUiTransition {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            easing: RoundUiEasing::default(),
+        }
+    }
+
+    pub fn with_easing(mut self, easing: RoundUiEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Per-node state of a `RoundUiTransition`, inserted automatically.
+///
+/// The node displays `animated`, a material owned by the node, which is interpolated from a snapshot of the material
+/// at the start of the transition towards the `target` material.
+#[derive(Component, Debug)]
+pub struct RoundUiTransitionState<M: UiMaterial> {
+    animated: Handle<M>,
+    from: M,
+    target: Handle<M>,
+    elapsed: f32,
+}
+
+impl<M: UiMaterial> RoundUiTransitionState<M> {
+    /// The material the node is transitioning to.
+    pub fn target(&self) -> &Handle<M> {
+        &self.target
+    }
+}
+
+/// A material whose properties can be interpolated by `RoundUiTransition`.
+pub(crate) trait RoundUiTransitionMaterial: UiMaterial + Clone {
+    /// Interpolates from `self` towards `target`. Properties that can't be interpolated are taken from `target`.
+    fn interpolate(&self, target: &Self, t: f32) -> Self;
+}
+
+/// Adds the systems animating `RoundUiTransition` nodes with the material `M`.
+pub(crate) fn add_transition_systems<M: RoundUiTransitionMaterial>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        (start_transitions::<M>, update_transitions::<M>)
+            .chain()
+            .in_set(RoundUiSystem::Transition),
+    );
+}
+
+/// Starts a transition when the material handle of a node changes, and points the node back to its animated
+/// material.
+#[allow(clippy::type_complexity)]
+fn start_transitions<M: RoundUiTransitionMaterial>(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &RoundUiTransition,
+            &mut Handle<M>,
+            Option<&mut RoundUiTransitionState<M>>,
+        ),
+        Or<(Changed<Handle<M>>, Without<RoundUiTransitionState<M>>)>,
+    >,
+    mut materials: ResMut<Assets<M>>,
+) {
+    for (entity, transition, mut handle, state) in query.iter_mut() {
+        match state {
+            Some(mut state) => {
+                if *handle == state.animated {
+                    continue;
+                }
+                // Start from wherever the previous transition got to
+                let Some(from) = materials.get(&state.animated).cloned() else {
+                    continue;
+                };
+                state.from = from;
+                state.target = handle.clone();
+                state.elapsed = 0.;
+                if transition.duration <= 0. {
+                    // Nothing to animate, so show the new material right away
+                    state.elapsed = f32::INFINITY;
+                    if let Some(target) = materials.get(&*handle).cloned() {
+                        if let Some(animated) = materials.get_mut(&state.animated) {
+                            *animated = target;
+                        }
+                    }
+                }
+                *handle = state.animated.clone();
+            }
+            None => {
+                // The first material is shown without a transition
+                let Some(material) = materials.get(&*handle).cloned() else {
+                    continue;
+                };
+                let animated = materials.add(material.clone());
+                commands.entity(entity).insert(RoundUiTransitionState {
+                    animated: animated.clone(),
+                    from: material,
+                    target: handle.clone(),
+                    elapsed: f32::INFINITY,
+                });
+                *handle = animated;
+            }
+        }
+    }
+}
+
+/// Advances the transitions, and keeps finished transitions in sync with changes to their target material.
+fn update_transitions<M: RoundUiTransitionMaterial>(
+    time: Res<Time>,
+    mut events: EventReader<AssetEvent<M>>,
+    mut query: Query<(&RoundUiTransition, &mut RoundUiTransitionState<M>)>,
+    mut materials: ResMut<Assets<M>>,
+) {
+    let modified: HashSet<AssetId<M>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (transition, mut state) in query.iter_mut() {
+        let finished = state.elapsed >= transition.duration;
+        if finished && !modified.contains(&state.target.id()) {
+            continue;
+        }
+        let Some(target) = materials.get(&state.target) else {
+            continue;
+        };
+
+        let material = if finished {
+            target.clone()
+        } else {
+            state.elapsed += time.delta_seconds();
+            let t = transition.easing.ease(state.elapsed / transition.duration);
+            state.from.interpolate(target, t)
+        };
+        if let Some(animated) = materials.get_mut(&state.animated) {
+            *animated = material;
+        }
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Interpolates between two colors in the Oklab color space, with premultiplied alpha so fully transparent colors
+/// don't tint the result.
+pub(crate) fn mix_color(a: LinearRgba, b: LinearRgba, t: f32) -> LinearRgba {
+    let alpha = lerp(a.alpha, b.alpha, t);
+    if alpha <= 0. {
+        return LinearRgba::NONE;
+    }
+    let (lab_a, lab_b) = (Oklaba::from(a), Oklaba::from(b));
+    let mix = |x: f32, y: f32| lerp(x * a.alpha, y * b.alpha, t) / alpha;
+    Oklaba::new(
+        mix(lab_a.lightness, lab_b.lightness),
+        mix(lab_a.a, lab_b.a),
+        mix(lab_a.b, lab_b.b),
+        alpha,
+    )
+    .into()
+}
+
+/// Interpolates between two shadows.
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+pub(crate) fn mix_shadow(a: RoundUiShadow, b: RoundUiShadow, t: f32) -> RoundUiShadow {
+    RoundUiShadow {
+        color: mix_color(a.color, b.color, t),
+        offset: a.offset.lerp(b.offset, t),
+        blur: lerp(a.blur, b.blur, t),
+        spread: lerp(a.spread, b.spread, t),
+    }
+}
+
+/// Interpolates between two outlines.
+#[cfg(any(feature = "round_rect", feature = "superellipse", feature = "polygon"))]
+pub(crate) fn mix_outline(a: RoundUiOutline, b: RoundUiOutline, t: f32) -> RoundUiOutline {
+    RoundUiOutline {
+        color: mix_color(a.color, b.color, t),
+        width: lerp(a.width, b.width, t),
+        offset: lerp(a.offset, b.offset, t),
+    }
+}
+
+/// Interpolates between two `f32`s.
+pub(crate) fn mix_f32(a: f32, b: f32, t: f32) -> f32 {
+    lerp(a, b, t)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn easing_endpoints() {
+        let easings = [
+            RoundUiEasing::Linear,
+            RoundUiEasing::EaseIn,
+            RoundUiEasing::EaseOut,
+            RoundUiEasing::EaseInOut,
+            RoundUiEasing::CubicBezier(Vec2::new(0.25, 0.1), Vec2::new(0.25, 1.)),
+        ];
+        for easing in easings {
+            assert!(easing.ease(0.).abs() < 1e-4, "{easing:?}");
+            assert!((easing.ease(1.) - 1.).abs() < 1e-4, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn mix_color_ignores_transparent_color() {
+        let red = LinearRgba::RED;
+        let half = mix_color(LinearRgba::NONE, red, 0.5);
+        assert!((half.alpha - 0.5).abs() < 1e-4);
+        assert!((half.red - 1.).abs() < 1e-3);
+        assert!(half.green.abs() < 1e-3 && half.blue.abs() < 1e-3);
+        assert_eq!(mix_color(red, LinearRgba::NONE, 1.), LinearRgba::NONE);
+    }
+}
+
+#[cfg(all(test, feature = "round_rect"))]
+mod material_test {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{round_rect::RoundRectUiMaterial, test_util::*};
+
+    #[test]
+    fn shared_materials_animate_per_node() {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )))
+        .add_systems(
+            Update,
+            (
+                start_transitions::<RoundRectUiMaterial>,
+                update_transitions::<RoundRectUiMaterial>,
+            )
+                .chain(),
+        );
+
+        let small = add_material(&mut app, RoundRectUiMaterial::default());
+        let large = add_material(
+            &mut app,
+            RoundRectUiMaterial {
+                border_radius: Vec4::splat(20.),
+                ..default()
+            },
+        );
+        let transition = RoundUiTransition::new(1.).with_easing(RoundUiEasing::Linear);
+        let moving = app.world_mut().spawn((small.clone(), transition)).id();
+        let resting = app.world_mut().spawn((small.clone(), transition)).id();
+
+        // Each node gets its own animated material
+        app.update();
+        let handle = material_handle::<RoundRectUiMaterial>;
+        let moving_handle = handle(&app, moving);
+        assert_ne!(moving_handle, small);
+        assert_ne!(moving_handle, handle(&app, resting));
+
+        *app.world_mut()
+            .get_mut::<Handle<RoundRectUiMaterial>>(moving)
+            .unwrap() = large.clone();
+        app.update();
+        app.update();
+
+        let radius = material(&app, &moving_handle).border_radius.x;
+        assert!(radius > 0. && radius < 20., "radius: {radius}");
+        assert_eq!(handle(&app, moving), moving_handle);
+        assert_eq!(material(&app, &handle(&app, resting)).border_radius.x, 0.);
+        let state = app
+            .world()
+            .get::<RoundUiTransitionState<RoundRectUiMaterial>>(moving)
+            .unwrap();
+        assert_eq!(state.target(), &large);
+    }
+
+    #[test]
+    fn zero_duration_shows_new_material_immediately() {
+        let mut app = material_test_app::<RoundRectUiMaterial>();
+        app.add_systems(
+            Update,
+            (
+                start_transitions::<RoundRectUiMaterial>,
+                update_transitions::<RoundRectUiMaterial>,
+            )
+                .chain(),
+        );
+
+        let small = add_material(&mut app, RoundRectUiMaterial::default());
+        let large = add_material(
+            &mut app,
+            RoundRectUiMaterial {
+                border_radius: Vec4::splat(20.),
+                ..default()
+            },
+        );
+        let entity = app
+            .world_mut()
+            .spawn((small, RoundUiTransition::new(0.)))
+            .id();
+        app.update();
+
+        *app.world_mut()
+            .get_mut::<Handle<RoundRectUiMaterial>>(entity)
+            .unwrap() = large;
+        app.update();
+
+        let handle = material_handle::<RoundRectUiMaterial>(&app, entity);
+        assert_eq!(material(&app, &handle).border_radius, Vec4::splat(20.));
+    }
+}
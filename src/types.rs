@@ -1,4 +1,21 @@
-use bevy::prelude::*;
+use bevy::{ecs::schedule::SystemSet, prelude::*, render::render_resource::ShaderType};
+
+/// System sets for the systems added by the material plugins, which all run in `PostUpdate`.
+#[derive(SystemSet, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundUiSystem {
+    /// Turns style components into materials, before the layout is computed.
+    Style,
+    /// Swaps materials to match the interaction state of nodes, before the layout is computed.
+    Interaction,
+    /// Starts and advances material transitions, before the layout is computed.
+    Transition,
+    /// Advances `RoundUiCooldown` timers and writes them to the sweep masks of materials, before the layout is computed.
+    Cooldown,
+    /// Keeps the padding of nodes in sync with their materials, before the layout is computed.
+    Padding,
+    /// Resolves responsive sizes against the computed layout.
+    Responsive,
+}
 
 /// Utility struct for constructing the `offset: Vec4` property of the material and `padding: UiRect` for the node.
 ///
@@ -118,6 +135,19 @@ impl RoundUiOffset {
     }
 }
 
+impl std::ops::Add for RoundUiOffset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            top: self.top + rhs.top,
+            left: self.left + rhs.left,
+            bottom: self.bottom + rhs.bottom,
+            right: self.right + rhs.right,
+        }
+    }
+}
+
 impl From<RoundUiOffset> for Vec4 {
     fn from(val: RoundUiOffset) -> Self {
         Vec4::new(val.top, val.left, val.bottom, val.right)
@@ -280,6 +310,868 @@ impl From<Vec4> for RoundUiBorder {
     }
 }
 
+/// The shape of a corner, sized by its border radius.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiCornerType {
+    /// A circular arc.
+    #[default]
+    Round,
+    /// A straight 45° cut.
+    Chamfer,
+    /// A square cut into the corner.
+    Notch,
+    /// A concave circular arc cut into the corner.
+    Scoop,
+}
+
+impl From<RoundUiCornerType> for u32 {
+    fn from(val: RoundUiCornerType) -> Self {
+        match val {
+            RoundUiCornerType::Round => 0,
+            RoundUiCornerType::Chamfer => 1,
+            RoundUiCornerType::Notch => 2,
+            RoundUiCornerType::Scoop => 3,
+        }
+    }
+}
+
+/// The side a progress fill starts from, for the `fill_direction` property of the materials.
+///
+/// # Example:
+///
+/// ```
+/// use bevy_round_ui::prelude::RoundUiFillDirection;
+///
+/// // Health bars fill from left to right by default
+/// assert_eq!(RoundUiFillDirection::default(), RoundUiFillDirection::LeftToRight);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiFillDirection {
+    /// Fill from the left side towards the right.
+    #[default]
+    LeftToRight,
+    /// Fill from the right side towards the left.
+    RightToLeft,
+    /// Fill from the top side towards the bottom.
+    TopToBottom,
+    /// Fill from the bottom side towards the top.
+    BottomToTop,
+}
+
+impl From<RoundUiFillDirection> for u32 {
+    fn from(val: RoundUiFillDirection) -> Self {
+        match val {
+            RoundUiFillDirection::LeftToRight => 0,
+            RoundUiFillDirection::RightToLeft => 1,
+            RoundUiFillDirection::TopToBottom => 2,
+            RoundUiFillDirection::BottomToTop => 3,
+        }
+    }
+}
+
+/// The shape of the ends of an arc, for the `cap` property of the `ArcUiMaterial`.
+///
+/// # Example:
+///
+/// ```
+/// use bevy_round_ui::prelude::RoundUiArcCap;
+///
+/// // Arcs have round ends by default
+/// assert_eq!(RoundUiArcCap::default(), RoundUiArcCap::Round);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiArcCap {
+    /// Ends the arc with a half circle, reaching past the end angle by half the thickness.
+    #[default]
+    Round,
+    /// Cuts the arc off straight along the radius at the end angle.
+    Flat,
+}
+
+impl From<RoundUiArcCap> for u32 {
+    fn from(val: RoundUiArcCap) -> Self {
+        match val {
+            RoundUiArcCap::Round => 0,
+            RoundUiArcCap::Flat => 1,
+        }
+    }
+}
+
+/// Utility struct for constructing the `corners` property of the material, with the shape of each corner.
+///
+/// Corners that aren't set are round, so corner types can be mixed on a single node.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::UVec4;
+/// use bevy_round_ui::prelude::{RoundUiCornerType, RoundUiCorners};
+///
+/// let corners = RoundUiCorners::top_left(RoundUiCornerType::Chamfer);
+/// assert_eq!(corners.top_left, RoundUiCornerType::Chamfer);
+/// assert_eq!(corners.bottom_right, RoundUiCornerType::Round);
+///
+/// // The same order as the `Vec4` of `RoundUiBorder`
+/// let corners_vec: UVec4 = corners.into();
+/// assert_eq!(corners_vec, UVec4::new(0, 0, 0, 1));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub struct RoundUiCorners {
+    pub top_left: RoundUiCornerType,
+    pub top_right: RoundUiCornerType,
+    pub bottom_left: RoundUiCornerType,
+    pub bottom_right: RoundUiCornerType,
+}
+
+impl RoundUiCorners {
+    pub fn all(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: corner,
+            top_right: corner,
+            bottom_left: corner,
+            bottom_right: corner,
+        }
+    }
+
+    pub fn top_left(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: corner,
+            top_right: RoundUiCornerType::Round,
+            bottom_left: RoundUiCornerType::Round,
+            bottom_right: RoundUiCornerType::Round,
+        }
+    }
+
+    pub fn top_right(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: RoundUiCornerType::Round,
+            top_right: corner,
+            bottom_left: RoundUiCornerType::Round,
+            bottom_right: RoundUiCornerType::Round,
+        }
+    }
+
+    pub fn bottom_left(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: RoundUiCornerType::Round,
+            top_right: RoundUiCornerType::Round,
+            bottom_left: corner,
+            bottom_right: RoundUiCornerType::Round,
+        }
+    }
+
+    pub fn bottom_right(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: RoundUiCornerType::Round,
+            top_right: RoundUiCornerType::Round,
+            bottom_left: RoundUiCornerType::Round,
+            bottom_right: corner,
+        }
+    }
+
+    pub fn top(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: corner,
+            top_right: corner,
+            bottom_left: RoundUiCornerType::Round,
+            bottom_right: RoundUiCornerType::Round,
+        }
+    }
+
+    pub fn bottom(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: RoundUiCornerType::Round,
+            top_right: RoundUiCornerType::Round,
+            bottom_left: corner,
+            bottom_right: corner,
+        }
+    }
+
+    pub fn left(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: corner,
+            top_right: RoundUiCornerType::Round,
+            bottom_left: corner,
+            bottom_right: RoundUiCornerType::Round,
+        }
+    }
+
+    pub fn right(corner: RoundUiCornerType) -> Self {
+        Self {
+            top_left: RoundUiCornerType::Round,
+            top_right: corner,
+            bottom_left: RoundUiCornerType::Round,
+            bottom_right: corner,
+        }
+    }
+}
+
+impl From<RoundUiCorners> for UVec4 {
+    fn from(val: RoundUiCorners) -> Self {
+        UVec4::new(
+            val.bottom_right.into(),
+            val.top_right.into(),
+            val.bottom_left.into(),
+            val.top_left.into(),
+        )
+    }
+}
+
+/// A length that is resolved against the size of a node or the viewport, used by `RoundUiResponsive`.
+///
+/// Values resolve to logical pixels. Offsets and border thicknesses use them as they are, so `RoundUiVal::Px(20.0)` is
+/// the same as `20.0`, while `RoundUiBorderVal` treats them as corner radii and doubles them to get the units of
+/// `RoundUiBorder`.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::{Val, Vec2};
+/// use bevy_round_ui::prelude::RoundUiVal;
+///
+/// let node_size = Vec2::new(200., 100.);
+/// let viewport_size = Vec2::new(1280., 720.);
+///
+/// assert_eq!(RoundUiVal::Px(20.).resolve(node_size, viewport_size), 20.);
+/// assert_eq!(RoundUiVal::Percent(50.).resolve(node_size, viewport_size), 50.);
+/// assert_eq!(RoundUiVal::PercentWidth(50.).resolve(node_size, viewport_size), 100.);
+/// assert_eq!(RoundUiVal::Vh(10.).resolve(node_size, viewport_size), 72.);
+/// assert_eq!(RoundUiVal::from(Val::Vw(10.)), RoundUiVal::Vw(10.));
+/// ```
+#[derive(Copy, Clone, Debug, Reflect, PartialEq)]
+pub enum RoundUiVal {
+    /// A value in pixels.
+    Px(f32),
+    /// A percentage of the shortest side of the node.
+    Percent(f32),
+    /// A percentage of the width of the node.
+    PercentWidth(f32),
+    /// A percentage of the height of the node.
+    PercentHeight(f32),
+    /// A percentage of the width of the viewport.
+    Vw(f32),
+    /// A percentage of the height of the viewport.
+    Vh(f32),
+    /// A percentage of the shortest side of the viewport.
+    VMin(f32),
+    /// A percentage of the longest side of the viewport.
+    VMax(f32),
+}
+
+impl Default for RoundUiVal {
+    fn default() -> Self {
+        Self::Px(0.)
+    }
+}
+
+impl RoundUiVal {
+    /// Resolves the value against the size of the node and the size of the viewport, in logical pixels.
+    pub fn resolve(self, node_size: Vec2, viewport_size: Vec2) -> f32 {
+        match self {
+            Self::Px(value) => value,
+            Self::Percent(percent) => node_size.min_element() * percent / 100.,
+            Self::PercentWidth(percent) => node_size.x * percent / 100.,
+            Self::PercentHeight(percent) => node_size.y * percent / 100.,
+            Self::Vw(percent) => viewport_size.x * percent / 100.,
+            Self::Vh(percent) => viewport_size.y * percent / 100.,
+            Self::VMin(percent) => viewport_size.min_element() * percent / 100.,
+            Self::VMax(percent) => viewport_size.max_element() * percent / 100.,
+        }
+    }
+
+    /// Converts the value to a `Val`, e.g. for the padding of a node. Pixels and viewport units are kept as they are,
+    /// while percentages of the node have no `Val` equivalent, so they are resolved to pixels against `node_size`.
+    pub fn to_val(self, node_size: Vec2) -> Val {
+        match self {
+            Self::Px(value) => Val::Px(value),
+            Self::Vw(percent) => Val::Vw(percent),
+            Self::Vh(percent) => Val::Vh(percent),
+            Self::VMin(percent) => Val::VMin(percent),
+            Self::VMax(percent) => Val::VMax(percent),
+            Self::Percent(_) | Self::PercentWidth(_) | Self::PercentHeight(_) => {
+                Val::Px(self.resolve(node_size, Vec2::ZERO))
+            }
+        }
+    }
+}
+
+impl From<f32> for RoundUiVal {
+    fn from(value: f32) -> Self {
+        Self::Px(value)
+    }
+}
+
+impl From<Val> for RoundUiVal {
+    /// Converts a `Val`. `Val::Auto` is treated as zero, and `Val::Percent` as a percentage of the shortest side of
+    /// the node.
+    fn from(val: Val) -> Self {
+        match val {
+            Val::Auto => Self::Px(0.),
+            Val::Px(value) => Self::Px(value),
+            Val::Percent(percent) => Self::Percent(percent),
+            Val::Vw(percent) => Self::Vw(percent),
+            Val::Vh(percent) => Self::Vh(percent),
+            Val::VMin(percent) => Self::VMin(percent),
+            Val::VMax(percent) => Self::VMax(percent),
+        }
+    }
+}
+
+/// Responsive version of `RoundUiOffset`, with a `RoundUiVal` for each side.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::{UiRect, Val, Vec2};
+/// use bevy_round_ui::prelude::{RoundUiOffsetVal, RoundUiVal};
+///
+/// let offset = RoundUiOffsetVal {
+///     bottom: RoundUiVal::PercentHeight(10.),
+///     ..RoundUiOffsetVal::all(RoundUiVal::VMin(1.))
+/// };
+///
+/// let padding = offset.to_ui_rect(Vec2::new(200., 100.));
+/// assert_eq!(padding.top, Val::VMin(1.));
+/// assert_eq!(padding.bottom, Val::Px(10.));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq)]
+pub struct RoundUiOffsetVal {
+    pub top: RoundUiVal,
+    pub left: RoundUiVal,
+    pub bottom: RoundUiVal,
+    pub right: RoundUiVal,
+}
+
+impl RoundUiOffsetVal {
+    pub fn all(amount: impl Into<RoundUiVal>) -> Self {
+        let amount = amount.into();
+        Self {
+            top: amount,
+            left: amount,
+            bottom: amount,
+            right: amount,
+        }
+    }
+
+    /// Resolves each side against the size of the node and the size of the viewport.
+    pub fn resolve(&self, node_size: Vec2, viewport_size: Vec2) -> RoundUiOffset {
+        RoundUiOffset {
+            top: self.top.resolve(node_size, viewport_size),
+            left: self.left.resolve(node_size, viewport_size),
+            bottom: self.bottom.resolve(node_size, viewport_size),
+            right: self.right.resolve(node_size, viewport_size),
+        }
+    }
+
+    /// Converts each side to a `Val` with `RoundUiVal::to_val`, e.g. for the padding of a node. Viewport units stay
+    /// responsive, while percentages of the node are resolved to pixels against `node_size`.
+    pub fn to_ui_rect(&self, node_size: Vec2) -> UiRect {
+        UiRect {
+            left: self.left.to_val(node_size),
+            right: self.right.to_val(node_size),
+            top: self.top.to_val(node_size),
+            bottom: self.bottom.to_val(node_size),
+        }
+    }
+}
+
+impl From<RoundUiOffset> for RoundUiOffsetVal {
+    fn from(val: RoundUiOffset) -> Self {
+        Self {
+            top: val.top.into(),
+            left: val.left.into(),
+            bottom: val.bottom.into(),
+            right: val.right.into(),
+        }
+    }
+}
+
+/// Responsive version of `RoundUiBorder`, with a `RoundUiVal` for each corner.
+///
+/// Each value is the radius of its corner, so `RoundUiVal::Px(10.0)` gives a 10 pixel radius and
+/// `RoundUiVal::Percent(50.0)` gives fully rounded ends. `RoundUiBorder` values are twice the radius of their corner,
+/// so the resolved values are doubled.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::Vec2;
+/// use bevy_round_ui::prelude::{RoundUiBorder, RoundUiBorderVal, RoundUiVal};
+///
+/// let node_size = Vec2::new(200., 100.);
+///
+/// let border = RoundUiBorderVal::all(RoundUiVal::Px(10.));
+/// assert_eq!(border.resolve(node_size, Vec2::ZERO), RoundUiBorder::all(20.));
+///
+/// let border = RoundUiBorderVal::all(RoundUiVal::Percent(50.));
+/// assert_eq!(border.resolve(node_size, Vec2::ZERO), RoundUiBorder::all(100.));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq)]
+pub struct RoundUiBorderVal {
+    pub top_left: RoundUiVal,
+    pub top_right: RoundUiVal,
+    pub bottom_left: RoundUiVal,
+    pub bottom_right: RoundUiVal,
+}
+
+impl RoundUiBorderVal {
+    pub fn all(radius: impl Into<RoundUiVal>) -> Self {
+        let radius = radius.into();
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
+        }
+    }
+
+    /// Resolves the radius of each corner against the size of the node and the size of the viewport, and doubles it
+    /// to get the units of `RoundUiBorder`.
+    pub fn resolve(&self, node_size: Vec2, viewport_size: Vec2) -> RoundUiBorder {
+        let resolve = |radius: RoundUiVal| radius.resolve(node_size, viewport_size) * 2.;
+        RoundUiBorder {
+            top_left: resolve(self.top_left),
+            top_right: resolve(self.top_right),
+            bottom_left: resolve(self.bottom_left),
+            bottom_right: resolve(self.bottom_right),
+        }
+    }
+}
+
+impl From<RoundUiBorder> for RoundUiBorderVal {
+    /// Converts a `RoundUiBorder` to the pixel radius of each corner, so it resolves back to the same values.
+    fn from(val: RoundUiBorder) -> Self {
+        Self {
+            top_left: RoundUiVal::Px(val.top_left / 2.),
+            top_right: RoundUiVal::Px(val.top_right / 2.),
+            bottom_left: RoundUiVal::Px(val.bottom_left / 2.),
+            bottom_right: RoundUiVal::Px(val.bottom_right / 2.),
+        }
+    }
+}
+
+pub use shader_types::{RoundUiOutline, RoundUiShadow};
+
+// `dead_code` is allowed for the `ShaderType` derive, as in `shared::shader_types`
+#[allow(dead_code)]
+mod shader_types {
+    use super::*;
+
+    /// Utility struct describing a drop shadow or an inset shadow, drawn from the same shape as the material so it follows
+    /// each corner radius.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use bevy::prelude::{LinearRgba, Vec2};
+    /// use bevy_round_ui::prelude::RoundUiShadow;
+    ///
+    /// let shadow = RoundUiShadow::new(LinearRgba::new(0., 0., 0., 0.5), Vec2::new(0., 4.), 8.);
+    /// assert_eq!(shadow.spread, 0.);
+    ///
+    /// // The default shadow is fully transparent, so nothing is drawn
+    /// assert_eq!(RoundUiShadow::default().color, LinearRgba::NONE);
+    /// ```
+    #[derive(Copy, Clone, Debug, Reflect, PartialEq, ShaderType)]
+    #[type_path = "bevy_round_ui::types"]
+    pub struct RoundUiShadow {
+        /// The color of the shadow. No shadow is drawn if the alpha is `0.0`.
+        pub color: LinearRgba,
+        /// The offset of the shadow in pixels, where positive values move it right and down.
+        pub offset: Vec2,
+        /// The blur radius in pixels.
+        pub blur: f32,
+        /// The distance in pixels to grow the shadow by on every side. Negative values shrink it.
+        ///
+        /// For inset shadows, this grows the shadow inwards.
+        pub spread: f32,
+    }
+
+    /// Utility struct describing an outline drawn around the outside of a material, like a CSS `outline`.
+    ///
+    /// The outline follows the shape of the material, with each corner radius grown by the `offset`. It is rendered
+    /// outside the bounds of the node, so it doesn't affect the layout, e.g. for focus rings and selection highlights.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use bevy::prelude::LinearRgba;
+    /// use bevy_round_ui::prelude::RoundUiOutline;
+    ///
+    /// // A 2 pixel outline with a 3 pixel gap between it and the shape
+    /// let outline = RoundUiOutline::new(LinearRgba::WHITE, 2.).with_offset(3.);
+    /// assert_eq!(outline.width, 2.);
+    ///
+    /// // The default outline is fully transparent, so nothing is drawn
+    /// assert_eq!(RoundUiOutline::default().color, LinearRgba::NONE);
+    /// ```
+    #[derive(Copy, Clone, Debug, Reflect, PartialEq, ShaderType)]
+    #[type_path = "bevy_round_ui::types"]
+    pub struct RoundUiOutline {
+        /// The color of the outline. No outline is drawn if the alpha is `0.0`.
+        pub color: LinearRgba,
+        /// The width of the outline in pixels.
+        pub width: f32,
+        /// The gap in pixels between the edge of the shape and the outline. Negative values move the outline over the
+        /// shape.
+        pub offset: f32,
+    }
+}
+
+impl Default for RoundUiShadow {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::NONE,
+            offset: Vec2::ZERO,
+            blur: 0.,
+            spread: 0.,
+        }
+    }
+}
+
+impl RoundUiShadow {
+    pub fn new(color: impl Into<LinearRgba>, offset: Vec2, blur: f32) -> Self {
+        Self {
+            color: color.into(),
+            offset,
+            blur,
+            spread: 0.,
+        }
+    }
+
+    pub fn with_spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+}
+
+impl Default for RoundUiOutline {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::NONE,
+            width: 0.,
+            offset: 0.,
+        }
+    }
+}
+
+impl RoundUiOutline {
+    pub fn new(color: impl Into<LinearRgba>, width: f32) -> Self {
+        Self {
+            color: color.into(),
+            width,
+            offset: 0.,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// The direction the edge of a `RoundUiSweepMask` moves around in.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiSweepDirection {
+    /// Sweep clockwise, like the hands of a clock.
+    #[default]
+    Clockwise,
+    /// Sweep counterclockwise.
+    CounterClockwise,
+}
+
+impl From<RoundUiSweepDirection> for u32 {
+    fn from(val: RoundUiSweepDirection) -> Self {
+        match val {
+            RoundUiSweepDirection::Clockwise => 0,
+            RoundUiSweepDirection::CounterClockwise => 1,
+        }
+    }
+}
+
+/// Utility struct describing a "clock wipe" overlay drawn over a material, e.g. for the cooldown of an ability button.
+///
+/// The overlay is a sector around the center of the inner button area, clipped to the shape, which covers `fraction`
+/// of a full turn. As the fraction goes down from `1.0` to `0.0`, its edge sweeps around in `direction`, starting from
+/// `start_angle`, revealing the shape underneath. Use a `RoundUiCooldown` to drive the fraction from a timer.
+///
+/// # Example:
+///
+/// ```
+/// use bevy::prelude::LinearRgba;
+/// use bevy_round_ui::prelude::{RoundUiSweepDirection, RoundUiSweepMask};
+///
+/// // Darken three quarters of the shape, sweeping counterclockwise from the top
+/// let mask = RoundUiSweepMask::new(LinearRgba::new(0., 0., 0., 0.6), 0.75)
+///     .with_direction(RoundUiSweepDirection::CounterClockwise);
+/// assert_eq!(mask.start_angle, 0.);
+///
+/// // The default mask doesn't cover anything
+/// assert_eq!(RoundUiSweepMask::default().fraction, 0.);
+/// ```
+#[derive(Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct RoundUiSweepMask {
+    /// The color of the overlay, drawn over the shape.
+    pub color: LinearRgba,
+    /// How much of a full turn the overlay covers, from `0.0` (nothing) to `1.0` (the whole shape).
+    pub fraction: f32,
+    /// The angle the sweep starts from, in radians. As in CSS, `0.0` points up and positive angles rotate clockwise.
+    pub start_angle: f32,
+    /// The direction the edge of the overlay moves in as the fraction goes down.
+    pub direction: RoundUiSweepDirection,
+}
+
+impl Default for RoundUiSweepMask {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::NONE,
+            fraction: 0.,
+            start_angle: 0.,
+            direction: RoundUiSweepDirection::default(),
+        }
+    }
+}
+
+impl RoundUiSweepMask {
+    pub fn new(color: impl Into<LinearRgba>, fraction: f32) -> Self {
+        Self {
+            color: color.into(),
+            fraction,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_start_angle(mut self, start_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: RoundUiSweepDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+/// The color space used to interpolate between the stops of a `RoundUiGradient`.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiColorSpace {
+    /// Interpolate the linear RGB channels.
+    #[default]
+    LinearRgb,
+    /// Interpolate in the perceptual Oklab color space, which avoids the dull or dark midpoints that linear RGB can
+    /// produce between saturated colors.
+    Oklab,
+}
+
+/// A color at a position along a `RoundUiGradient`.
+#[derive(Copy, Clone, Debug, Reflect, PartialEq)]
+pub struct RoundUiColorStop {
+    pub color: LinearRgba,
+    /// Position of the stop along the gradient, from `0.0` (start) to `1.0` (end).
+    pub position: f32,
+}
+
+impl RoundUiColorStop {
+    pub fn new(color: impl Into<LinearRgba>, position: f32) -> Self {
+        Self {
+            color: color.into(),
+            position,
+        }
+    }
+}
+
+/// The shape of a `RoundUiGradient`.
+///
+/// Positions are relative to the node, where `Vec2::ZERO` is the top-left corner and `Vec2::ONE` is the bottom-right
+/// corner. Angles are in radians where, as in CSS, `0.0` points up and positive angles rotate clockwise.
+#[derive(Copy, Clone, Debug, Reflect, PartialEq)]
+pub enum RoundUiFillMode {
+    /// Colors change along a straight line through the center of the node, pointing in the direction of `angle`.
+    /// The line is long enough for the corners of the node to reach both ends.
+    Linear { angle: f32 },
+    /// Colors change outwards from `center`, reaching the last stop at an ellipse with the given `radius`.
+    Radial { center: Vec2, radius: Vec2 },
+    /// Colors change clockwise around `center`, starting at `start_angle` and completing a full turn.
+    Conic { center: Vec2, start_angle: f32 },
+}
+
+impl Default for RoundUiFillMode {
+    fn default() -> Self {
+        Self::Linear { angle: 0. }
+    }
+}
+
+/// Utility struct describing a linear, radial or conic gradient, used in place of a solid color.
+///
+/// The gradient is drawn across the bounds of the node, and the result is dithered to avoid banding.
+///
+/// # Example:
+///
+/// ```
+/// use std::f32::consts::PI;
+///
+/// use bevy::prelude::{LinearRgba, Vec2};
+/// use bevy_round_ui::prelude::{RoundUiColorSpace, RoundUiFillMode, RoundUiGradient};
+///
+/// let gradient = RoundUiGradient::vertical(LinearRgba::WHITE, LinearRgba::BLACK)
+///     .with_color_space(RoundUiColorSpace::Oklab);
+/// assert_eq!(gradient.mode, RoundUiFillMode::Linear { angle: PI });
+/// assert_eq!(gradient.stops.len(), 2);
+/// assert_eq!(gradient.stops[1].position, 1.);
+///
+/// let spotlight = RoundUiGradient::radial(
+///     Vec2::new(0.5, 0.),
+///     Vec2::splat(0.75),
+///     [LinearRgba::WHITE, LinearRgba::NONE],
+/// );
+/// assert_eq!(spotlight.stops[0].position, 0.);
+/// ```
+#[derive(Clone, Debug, Default, Reflect, PartialEq)]
+pub struct RoundUiGradient {
+    /// The shape of the gradient.
+    pub mode: RoundUiFillMode,
+    /// The color stops, ordered by position. Only the first `RoundUiGradient::MAX_STOPS` stops are used.
+    pub stops: Vec<RoundUiColorStop>,
+    /// The color space used to interpolate between stops.
+    pub color_space: RoundUiColorSpace,
+}
+
+impl RoundUiGradient {
+    /// The maximum number of color stops supported by the shaders.
+    pub const MAX_STOPS: usize = 8;
+
+    /// Creates a gradient with the given mode and colors spaced evenly along it.
+    pub fn new(
+        mode: RoundUiFillMode,
+        colors: impl IntoIterator<Item = impl Into<LinearRgba>>,
+    ) -> Self {
+        let colors: Vec<LinearRgba> = colors.into_iter().map(Into::into).collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self {
+            mode,
+            stops: colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| RoundUiColorStop::new(color, i as f32 / last))
+                .collect(),
+            color_space: RoundUiColorSpace::default(),
+        }
+    }
+
+    /// Creates a linear gradient with the given angle and colors spaced evenly along it.
+    pub fn linear(angle: f32, colors: impl IntoIterator<Item = impl Into<LinearRgba>>) -> Self {
+        Self::new(RoundUiFillMode::Linear { angle }, colors)
+    }
+
+    /// Creates a radial gradient with colors spaced evenly from the center outwards.
+    pub fn radial(
+        center: Vec2,
+        radius: Vec2,
+        colors: impl IntoIterator<Item = impl Into<LinearRgba>>,
+    ) -> Self {
+        Self::new(RoundUiFillMode::Radial { center, radius }, colors)
+    }
+
+    /// Creates a conic gradient with colors spaced evenly around the center.
+    pub fn conic(
+        center: Vec2,
+        start_angle: f32,
+        colors: impl IntoIterator<Item = impl Into<LinearRgba>>,
+    ) -> Self {
+        Self::new(
+            RoundUiFillMode::Conic {
+                center,
+                start_angle,
+            },
+            colors,
+        )
+    }
+
+    /// Creates a gradient from the top to the bottom of the node.
+    pub fn vertical(top: impl Into<LinearRgba>, bottom: impl Into<LinearRgba>) -> Self {
+        Self::linear(std::f32::consts::PI, [top.into(), bottom.into()])
+    }
+
+    /// Creates a gradient from the left to the right of the node.
+    pub fn horizontal(left: impl Into<LinearRgba>, right: impl Into<LinearRgba>) -> Self {
+        Self::linear(std::f32::consts::FRAC_PI_2, [left.into(), right.into()])
+    }
+
+    pub fn with_color_space(mut self, color_space: RoundUiColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+}
+
+/// The style of the border of a material.
+///
+/// Dashes and dots follow the outline of the shape, including around its corners. Their spacing is stretched slightly
+/// so a whole number of them fit around the border. Animating the `phase` moves the pattern along the border, e.g. for
+/// "marching ants" selection outlines.
+///
+/// ```
+/// use bevy_round_ui::prelude::RoundUiBorderStyle;
+///
+/// let style = RoundUiBorderStyle::dashed(12., 6.).with_phase(3.);
+/// assert_eq!(
+///     style,
+///     RoundUiBorderStyle::Dashed {
+///         length: 12.,
+///         gap: 6.,
+///         phase: 3.,
+///     }
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq)]
+pub enum RoundUiBorderStyle {
+    /// A continuous border.
+    #[default]
+    Solid,
+    /// Dashes of `length` pixels separated by `gap` pixels, shifted along the border by `phase` pixels.
+    Dashed { length: f32, gap: f32, phase: f32 },
+    /// Round dots as wide as the border, separated by `gap` pixels and shifted along the border by `phase` pixels.
+    Dotted { gap: f32, phase: f32 },
+}
+
+impl RoundUiBorderStyle {
+    /// Creates a dashed border style.
+    pub fn dashed(length: f32, gap: f32) -> Self {
+        Self::Dashed {
+            length,
+            gap,
+            phase: 0.,
+        }
+    }
+
+    /// Creates a dotted border style.
+    pub fn dotted(gap: f32) -> Self {
+        Self::Dotted { gap, phase: 0. }
+    }
+
+    /// Returns the style with its pattern shifted along the border by `phase` pixels. Has no effect on solid borders.
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        match &mut self {
+            Self::Solid => {}
+            Self::Dashed { phase: p, .. } | Self::Dotted { phase: p, .. } => *p = phase,
+        }
+        self
+    }
+}
+
+/// How an image is fitted into the area of a material that it fills.
+#[derive(Copy, Clone, Debug, Default, Reflect, PartialEq, Eq, Hash)]
+pub enum RoundUiImageFit {
+    /// Scale the image, keeping its aspect ratio, until it covers the whole area. Parts of the image may be cropped.
+    #[default]
+    Cover,
+    /// Scale the image, keeping its aspect ratio, until it fits inside the area. Parts of the area may stay empty.
+    Contain,
+    /// Stretch the image to the size of the area, ignoring its aspect ratio.
+    Stretch,
+    /// Repeat the image at its original size, starting from the top left corner of the area.
+    Tile,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -335,4 +1227,82 @@ mod test {
         let offset_result: RoundUiOffset = offset_vec4.into();
         assert_eq!(offset_result, offset);
     }
+
+    #[test]
+    fn round_ui_offset_add() {
+        let offset = RoundUiOffset::bottom(5.) + RoundUiOffset::all(1.);
+        assert_eq!(
+            offset,
+            RoundUiOffset {
+                top: 1.,
+                left: 1.,
+                bottom: 6.,
+                right: 1.,
+            }
+        );
+    }
+
+    #[test]
+    fn round_ui_offset_val_resolves_each_side() {
+        let offset = RoundUiOffsetVal {
+            top: RoundUiVal::Px(1.),
+            left: RoundUiVal::PercentWidth(10.),
+            bottom: RoundUiVal::PercentHeight(10.),
+            right: RoundUiVal::VMax(1.),
+        };
+        let resolved = offset.resolve(Vec2::new(200., 100.), Vec2::new(1000., 500.));
+        assert_eq!(
+            resolved,
+            RoundUiOffset {
+                top: 1.,
+                left: 20.,
+                bottom: 10.,
+                right: 10.,
+            }
+        );
+    }
+
+    #[test]
+    fn round_ui_offset_val_to_ui_rect_keeps_viewport_units() {
+        let offset = RoundUiOffsetVal {
+            top: RoundUiVal::Px(1.),
+            left: RoundUiVal::Percent(10.),
+            bottom: RoundUiVal::Vh(2.),
+            right: RoundUiVal::VMax(3.),
+        };
+        assert_eq!(
+            offset.to_ui_rect(Vec2::new(200., 100.)),
+            UiRect {
+                left: Val::Px(10.),
+                right: Val::VMax(3.),
+                top: Val::Px(1.),
+                bottom: Val::Vh(2.),
+            }
+        );
+    }
+
+    #[test]
+    fn round_ui_border_val_from_border_is_unchanged() {
+        let border = RoundUiBorder::top(12.);
+        let border_val: RoundUiBorderVal = border.into();
+        assert_eq!(border_val.resolve(Vec2::ONE, Vec2::ONE), border);
+        assert_eq!(
+            RoundUiBorderVal::all(RoundUiVal::VMin(10.)).resolve(Vec2::ONE, Vec2::new(800., 600.)),
+            RoundUiBorder::all(120.)
+        );
+    }
+
+    #[test]
+    fn shader_types_keep_their_type_paths() {
+        use bevy::reflect::TypePath;
+
+        assert_eq!(
+            RoundUiShadow::type_path(),
+            "bevy_round_ui::types::RoundUiShadow"
+        );
+        assert_eq!(
+            RoundUiOutline::type_path(),
+            "bevy_round_ui::types::RoundUiOutline"
+        );
+    }
 }